@@ -1,13 +1,25 @@
-use super::Algorithm;
+use super::{
+    Algorithm, AlgorithmCategory, AlgorithmInfo, ArrayView, Element, StepEvent, Visualization,
+};
+
+const PSEUDOCODE: &[&str] = &[
+    "for i in 0 .. n - 1:",
+    "  for j in 0 .. n - i - 1:",
+    "    if a[j] > a[j + 1]:",
+    "      swap a[j], a[j + 1]",
+];
 
 #[derive(Debug, Clone)]
 pub struct BubbleSortState {
-    bars: Vec<i32>,
+    bars: Vec<Element>,
     i: usize,
     j: usize,
     complete: bool,
-    comparisons: Vec<(usize, usize)>,
+    events: Vec<StepEvent>,
+    finalized: Vec<bool>,
     current_indices: Vec<usize>,
+    line: Option<usize>,
+    note: String,
 }
 
 #[derive(Debug)]
@@ -22,14 +34,39 @@ impl Algorithm for BubbleSort {
         "Bubble Sort"
     }
 
+    fn short_name(&self) -> &'static str {
+        "Bubble"
+    }
+
+    fn category(&self) -> AlgorithmCategory {
+        AlgorithmCategory::Sorting
+    }
+
+    fn info(&self) -> AlgorithmInfo {
+        AlgorithmInfo {
+            description: "Repeatedly walks the array swapping adjacent out-of-order pairs, so the largest remaining value bubbles to the end of each pass; this version always runs every pass, with no early exit.",
+            best: "O(n²)",
+            average: "O(n²)",
+            worst: "O(n²)",
+            space: "O(1)",
+            per: None,
+            stable: Some(true),
+            in_place: Some(true),
+            adaptive: Some(false),
+        }
+    }
+
     fn initial_state(&self, bars: Vec<i32>) -> Self::State {
         BubbleSortState {
-            bars,
+            finalized: vec![false; bars.len()],
+            bars: Element::tag_all(bars),
             i: 0,
             j: 0,
             complete: false,
-            comparisons: Vec::new(),
+            events: Vec::new(),
             current_indices: Vec::new(),
+            line: None,
+            note: String::new(),
         }
     }
 
@@ -38,47 +75,98 @@ impl Algorithm for BubbleSort {
             return true;
         }
 
+        state.events.clear();
         let n = state.bars.len();
         if n <= 1 {
             state.complete = true;
+            state.finalized.fill(true);
+            state.events.push(StepEvent::Done);
             return true;
         }
 
-        state.comparisons.clear();
         state.current_indices.clear();
 
         if state.j < n - state.i - 1 {
+            if state.j == 0 {
+                state.events.push(StepEvent::RangeEntered {
+                    start: 0,
+                    end: n - state.i,
+                });
+            }
             state.current_indices = vec![state.j, state.j + 1];
-            state.comparisons.push((state.j, state.j + 1));
-
-            if state.bars[state.j] > state.bars[state.j + 1] {
-                state.bars.swap(state.j, state.j + 1);
+            state.events.push(StepEvent::Compare(state.j, state.j + 1));
+
+            let (j, left, right) = (
+                state.j,
+                state.bars[state.j].value,
+                state.bars[state.j + 1].value,
+            );
+            if left > right {
+                state.bars.swap(j, j + 1);
+                state.events.push(StepEvent::Swap(j, j + 1));
+                state.line = Some(3);
+                state.note = format!(
+                    "compare bars[{j}]={left} with bars[{}]={right} → swap",
+                    j + 1
+                );
+            } else {
+                state.line = Some(2);
+                state.note = format!(
+                    "compare bars[{j}]={left} with bars[{}]={right} → keep",
+                    j + 1
+                );
             }
 
             state.j += 1;
         } else {
             state.i += 1;
             state.j = 0;
+            state.line = Some(0);
+            state.finalized[n - state.i] = true;
+            state.events.push(StepEvent::PassComplete);
+            state.note = format!(
+                "pass {} done: bars[{}..] are in their final place",
+                state.i,
+                n - state.i
+            );
 
             if state.i >= n - 1 {
                 state.complete = true;
                 state.current_indices.clear();
-                state.comparisons.clear();
+                state.line = None;
+                state.finalized.fill(true);
+                state.events.push(StepEvent::Done);
             }
         }
 
         state.complete
     }
 
-    fn get_data(&self, state: &Self::State) -> Vec<i32> {
-        state.bars.clone()
+    fn visualization<'a>(&self, state: &'a Self::State) -> Visualization<'a> {
+        Visualization::Array(ArrayView {
+            elements: &state.bars,
+            current: &state.current_indices,
+            events: &state.events,
+            pivot: None,
+            finalized: &state.finalized,
+            active: (!state.complete).then_some((0, state.bars.len() - state.i)),
+            sorted_run: None,
+        })
+    }
+
+    fn narration<'a>(&self, state: &'a Self::State) -> &'a str {
+        &state.note
+    }
+
+    fn events<'a>(&self, state: &'a Self::State) -> &'a [StepEvent] {
+        &state.events
     }
 
-    fn get_comparisons(&self, state: &Self::State) -> Vec<(usize, usize)> {
-        state.comparisons.clone()
+    fn pseudocode(&self) -> &'static [&'static str] {
+        PSEUDOCODE
     }
 
-    fn get_current_indices(&self, state: &Self::State) -> Vec<usize> {
-        state.current_indices.clone()
+    fn code_line(&self, state: &Self::State) -> Option<usize> {
+        state.line
     }
 }
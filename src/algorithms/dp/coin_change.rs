@@ -0,0 +1,227 @@
+use super::{DpCell, DpTable};
+use crate::algorithms::input::{parse_numbers, split_fields};
+use crate::algorithms::{Algorithm, AlgorithmCategory, AlgorithmInfo, Visualization};
+
+const MAX_COINS: usize = 8;
+const MAX_AMOUNT: i64 = 40;
+
+const PSEUDOCODE: &[&str] = &[
+    "for i in 0 ..= n, a in 0 ..= amount:",
+    "  if a = 0: C[i][0] ← 0",
+    "  else if i = 0: C[0][a] ← ∞",
+    "  else if cᵢ > a: C[i][a] ← C[i-1][a]",
+    "  else: C[i][a] ← min(C[i-1][a], C[i][a - cᵢ] + 1)",
+    "trace back from C[n][amount]",
+];
+
+#[derive(Debug, Clone)]
+pub struct CoinChangeState {
+    coins: Vec<usize>,
+    amount: usize,
+    table: DpTable,
+    next: Option<(usize, usize)>,
+    complete: bool,
+    line: Option<usize>,
+}
+
+#[derive(Debug)]
+pub struct CoinChange {
+    coins: Vec<usize>,
+    amount: usize,
+}
+
+impl Default for CoinChange {
+    fn default() -> Self {
+        Self {
+            coins: vec![1, 2, 5],
+            amount: 11,
+        }
+    }
+}
+
+impl CoinChange {
+    fn finish(state: &mut CoinChangeState) {
+        let answer = (state.coins.len(), state.amount);
+        let Some(count) = state.table.value(answer.0, answer.1) else {
+            state.table.finish(
+                answer,
+                Vec::new(),
+                format!("Amount {} cannot be made from these coins", state.amount),
+            );
+            state.complete = true;
+            return;
+        };
+
+        let (mut row, mut col) = answer;
+        let mut path = vec![answer];
+        let mut used = Vec::new();
+
+        while col > 0 && row > 0 {
+            if state.table.value(row, col) == state.table.value(row - 1, col) {
+                row -= 1;
+            } else {
+                let coin = state.coins[row - 1];
+                used.push(coin.to_string());
+                col -= coin;
+            }
+
+            path.push((row, col));
+        }
+
+        state.table.finish(
+            answer,
+            path,
+            format!("{count} coin(s) for {}: {}", state.amount, used.join(" + ")),
+        );
+        state.complete = true;
+    }
+}
+
+impl Algorithm for CoinChange {
+    type State = CoinChangeState;
+
+    fn name(&self) -> &'static str {
+        "Coin Change"
+    }
+
+    fn short_name(&self) -> &'static str {
+        "Coins"
+    }
+
+    fn category(&self) -> AlgorithmCategory {
+        AlgorithmCategory::DynamicProgramming
+    }
+
+    fn info(&self) -> AlgorithmInfo {
+        AlgorithmInfo {
+            description: "Minimum number of coins for every amount up to the target, reusing each coin any number of times.",
+            best: "O(nA)",
+            average: "O(nA)",
+            worst: "O(nA)",
+            space: "O(nA)",
+            per: None,
+            stable: None,
+            in_place: None,
+            adaptive: None,
+        }
+    }
+
+    fn initial_state(&self, _bars: Vec<i32>) -> Self::State {
+        let row_labels = std::iter::once("—".to_string())
+            .chain(self.coins.iter().map(|coin| format!("coin {coin}")))
+            .collect();
+        let col_labels = (0..=self.amount).map(|a| a.to_string()).collect();
+
+        CoinChangeState {
+            coins: self.coins.clone(),
+            amount: self.amount,
+            table: DpTable::new(row_labels, col_labels),
+            next: Some((0, 0)),
+            complete: false,
+            line: None,
+        }
+    }
+
+    fn step(&self, state: &mut Self::State) -> bool {
+        if state.complete {
+            return true;
+        }
+
+        let Some((row, col)) = state.next else {
+            state.line = Some(5);
+            Self::finish(state);
+            return true;
+        };
+
+        let table = &state.table;
+        let (line, best, dependencies) = if col == 0 {
+            (1, Some(0), Vec::new())
+        } else if row == 0 {
+            (2, None, Vec::new())
+        } else {
+            let coin = state.coins[row - 1];
+            let without = table.value(row - 1, col);
+
+            if coin > col {
+                (3, without, vec![(row - 1, col)])
+            } else {
+                let with = table.value(row, col - coin).map(|count| count + 1);
+                let best = match (without, with) {
+                    (Some(a), Some(b)) => Some(a.min(b)),
+                    (a, b) => a.or(b),
+                };
+                (4, best, vec![(row - 1, col), (row, col - coin)])
+            }
+        };
+
+        let cell = best.map_or(DpCell::Infinite, DpCell::Value);
+        let count = best.map_or("∞".to_string(), |count| count.to_string());
+        let note = match line {
+            1 => format!("C[{row}][0] = 0: no coins needed for 0"),
+            2 => format!("C[0][{col}] = ∞: no coins available"),
+            3 => format!(
+                "coin {} > {col} → C[{row}][{col}] = C[{}][{col}] = {count}",
+                state.coins[row - 1],
+                row - 1
+            ),
+            _ => format!(
+                "coin {} → C[{row}][{col}] = min(skip, use once more) = {count}",
+                state.coins[row - 1]
+            ),
+        };
+        state.table.fill(row, col, cell, dependencies, note);
+        state.next = state.table.next_cell(row, col);
+        state.line = Some(line);
+
+        false
+    }
+
+    fn visualization<'a>(&self, state: &'a Self::State) -> Visualization<'a> {
+        Visualization::Table(&state.table)
+    }
+
+    fn narration<'a>(&self, state: &'a Self::State) -> &'a str {
+        &state.table.note
+    }
+
+    fn pseudocode(&self) -> &'static [&'static str] {
+        PSEUDOCODE
+    }
+
+    fn code_line(&self, state: &Self::State) -> Option<usize> {
+        state.line
+    }
+
+    fn input(&self) -> Option<String> {
+        let coins: Vec<String> = self.coins.iter().map(usize::to_string).collect();
+        Some(format!("{}; {}", coins.join(" "), self.amount))
+    }
+
+    fn input_format(&self) -> &'static str {
+        "coins; amount"
+    }
+
+    fn set_input(&mut self, input: &str) -> Result<(), String> {
+        let fields = split_fields(input, 2)?;
+        let coins = parse_numbers(fields[0])?;
+        let amount = parse_numbers(fields[1])?;
+
+        if coins.is_empty() || coins.len() > MAX_COINS {
+            return Err(format!("enter between 1 and {MAX_COINS} coins"));
+        }
+        if coins.iter().any(|&coin| coin < 1) {
+            return Err("coins must be positive".to_string());
+        }
+        let &[amount] = amount.as_slice() else {
+            return Err("amount must be a single number".to_string());
+        };
+        if !(1..=MAX_AMOUNT).contains(&amount) {
+            return Err(format!("amount must be between 1 and {MAX_AMOUNT}"));
+        }
+
+        self.coins = coins.into_iter().map(|coin| coin as usize).collect();
+        self.amount = amount as usize;
+
+        Ok(())
+    }
+}
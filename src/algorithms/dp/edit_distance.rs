@@ -0,0 +1,220 @@
+use super::{DpCell, DpTable, char_labels};
+use crate::algorithms::input::{parse_word, split_fields};
+use crate::algorithms::{Algorithm, AlgorithmCategory, AlgorithmInfo, Visualization};
+
+const MAX_LEN: usize = 24;
+
+const PSEUDOCODE: &[&str] = &[
+    "for i in 0 ..= n, j in 0 ..= m:",
+    "  if i = 0: D[0][j] ← j",
+    "  else if j = 0: D[i][0] ← i",
+    "  else: D[i][j] ← min(diag + cost, up + 1, left + 1)",
+    "trace back from D[n][m]",
+];
+
+#[derive(Debug, Clone)]
+pub struct EditDistanceState {
+    source: Vec<char>,
+    target: Vec<char>,
+    table: DpTable,
+    next: Option<(usize, usize)>,
+    complete: bool,
+    line: Option<usize>,
+}
+
+#[derive(Debug)]
+pub struct EditDistance {
+    source: String,
+    target: String,
+}
+
+impl Default for EditDistance {
+    fn default() -> Self {
+        Self {
+            source: "kitten".to_string(),
+            target: "sitting".to_string(),
+        }
+    }
+}
+
+impl EditDistance {
+    fn finish(state: &mut EditDistanceState) {
+        let answer = (state.source.len(), state.target.len());
+        let distance = state.table.value(answer.0, answer.1).unwrap_or(0);
+        let (mut row, mut col) = answer;
+        let mut path = vec![answer];
+        let (mut substitutions, mut insertions, mut deletions) = (0, 0, 0);
+
+        while row > 0 || col > 0 {
+            let current = state.table.value(row, col).unwrap_or(0);
+
+            if row > 0 && col > 0 {
+                let diagonal = state.table.value(row - 1, col - 1).unwrap_or(0);
+                let same = state.source[row - 1] == state.target[col - 1];
+
+                if same && current == diagonal {
+                    row -= 1;
+                    col -= 1;
+                    path.push((row, col));
+                    continue;
+                }
+
+                if !same && current == diagonal + 1 {
+                    substitutions += 1;
+                    row -= 1;
+                    col -= 1;
+                    path.push((row, col));
+                    continue;
+                }
+            }
+
+            if row > 0 && state.table.value(row - 1, col).unwrap_or(0) + 1 == current {
+                deletions += 1;
+                row -= 1;
+            } else {
+                insertions += 1;
+                col -= 1;
+            }
+
+            path.push((row, col));
+        }
+
+        state.table.finish(
+            answer,
+            path,
+            format!(
+                "Distance {distance}: {substitutions} substitution(s), {insertions} insertion(s), {deletions} deletion(s)"
+            ),
+        );
+        state.complete = true;
+    }
+}
+
+impl Algorithm for EditDistance {
+    type State = EditDistanceState;
+
+    fn name(&self) -> &'static str {
+        "Edit Distance"
+    }
+
+    fn short_name(&self) -> &'static str {
+        "Edit"
+    }
+
+    fn category(&self) -> AlgorithmCategory {
+        AlgorithmCategory::DynamicProgramming
+    }
+
+    fn info(&self) -> AlgorithmInfo {
+        AlgorithmInfo {
+            description: "Levenshtein distance: each cell is the cheapest insert, delete or substitute that turns one prefix into the other.",
+            best: "O(nm)",
+            average: "O(nm)",
+            worst: "O(nm)",
+            space: "O(nm)",
+            per: None,
+            stable: None,
+            in_place: None,
+            adaptive: None,
+        }
+    }
+
+    fn initial_state(&self, _bars: Vec<i32>) -> Self::State {
+        let source: Vec<char> = self.source.chars().collect();
+        let target: Vec<char> = self.target.chars().collect();
+        let table = DpTable::new(char_labels(&source), char_labels(&target));
+
+        EditDistanceState {
+            source,
+            target,
+            table,
+            next: Some((0, 0)),
+            complete: false,
+            line: None,
+        }
+    }
+
+    fn step(&self, state: &mut Self::State) -> bool {
+        if state.complete {
+            return true;
+        }
+
+        let Some((row, col)) = state.next else {
+            state.line = Some(4);
+            Self::finish(state);
+            return true;
+        };
+
+        let table = &state.table;
+        let (line, value, dependencies) = if row == 0 && col == 0 {
+            (1, 0, Vec::new())
+        } else if row == 0 {
+            (1, col as i64, vec![(0, col - 1)])
+        } else if col == 0 {
+            (2, row as i64, vec![(row - 1, 0)])
+        } else {
+            let cost = i64::from(state.source[row - 1] != state.target[col - 1]);
+            let substitute = table.value(row - 1, col - 1).unwrap_or(0) + cost;
+            let delete = table.value(row - 1, col).unwrap_or(0) + 1;
+            let insert = table.value(row, col - 1).unwrap_or(0) + 1;
+
+            (
+                3,
+                substitute.min(delete).min(insert),
+                vec![(row - 1, col - 1), (row - 1, col), (row, col - 1)],
+            )
+        };
+
+        let note = match line {
+            1 => format!("D[0][{col}] = {value}: insert {col} character(s)"),
+            2 => format!("D[{row}][0] = {value}: delete {row} character(s)"),
+            _ => format!(
+                "'{}' vs '{}' → D[{row}][{col}] = min(substitute, delete, insert) = {value}",
+                state.source[row - 1],
+                state.target[col - 1]
+            ),
+        };
+        state
+            .table
+            .fill(row, col, DpCell::Value(value), dependencies, note);
+        state.next = state.table.next_cell(row, col);
+        state.line = Some(line);
+
+        false
+    }
+
+    fn visualization<'a>(&self, state: &'a Self::State) -> Visualization<'a> {
+        Visualization::Table(&state.table)
+    }
+
+    fn narration<'a>(&self, state: &'a Self::State) -> &'a str {
+        &state.table.note
+    }
+
+    fn pseudocode(&self) -> &'static [&'static str] {
+        PSEUDOCODE
+    }
+
+    fn code_line(&self, state: &Self::State) -> Option<usize> {
+        state.line
+    }
+
+    fn input(&self) -> Option<String> {
+        Some(format!("{}; {}", self.source, self.target))
+    }
+
+    fn input_format(&self) -> &'static str {
+        "source string; target string"
+    }
+
+    fn set_input(&mut self, input: &str) -> Result<(), String> {
+        let fields = split_fields(input, 2)?;
+        let source = parse_word(fields[0], MAX_LEN)?;
+        let target = parse_word(fields[1], MAX_LEN)?;
+
+        self.source = source.into_iter().collect();
+        self.target = target.into_iter().collect();
+
+        Ok(())
+    }
+}
@@ -0,0 +1,286 @@
+use super::{DpCell, DpTable};
+use crate::algorithms::input::{parse_numbers, split_fields};
+use crate::algorithms::{Algorithm, AlgorithmCategory, AlgorithmInfo, Visualization};
+
+const MAX_ITEMS: usize = 12;
+const MAX_CAPACITY: i64 = 40;
+const MAX_VALUE: i64 = 9_999;
+
+const PSEUDOCODE: &[&str] = &[
+    "for i in 0 ..= n, w in 0 ..= W:",
+    "  if i = 0: K[0][w] ← 0",
+    "  else if wᵢ > w: K[i][w] ← K[i-1][w]",
+    "  else: K[i][w] ← max(K[i-1][w], K[i-1][w - wᵢ] + vᵢ)",
+    "trace back from K[n][W]",
+];
+
+#[derive(Debug, Clone)]
+pub struct KnapsackState {
+    weights: Vec<usize>,
+    values: Vec<i64>,
+    capacity: usize,
+    table: DpTable,
+    next: Option<(usize, usize)>,
+    complete: bool,
+    line: Option<usize>,
+}
+
+#[derive(Debug)]
+pub struct Knapsack {
+    weights: Vec<usize>,
+    values: Vec<i64>,
+    capacity: usize,
+}
+
+impl Default for Knapsack {
+    fn default() -> Self {
+        Self {
+            weights: vec![1, 3, 4, 5],
+            values: vec![1, 4, 5, 7],
+            capacity: 7,
+        }
+    }
+}
+
+impl Knapsack {
+    fn finish(state: &mut KnapsackState) {
+        let answer = (state.weights.len(), state.capacity);
+        let best = state.table.value(answer.0, answer.1).unwrap_or(0);
+        let mut capacity = state.capacity;
+        let mut path = vec![answer];
+        let mut chosen = Vec::new();
+
+        for item in (1..=state.weights.len()).rev() {
+            if state.table.value(item, capacity) != state.table.value(item - 1, capacity) {
+                chosen.push(item);
+                capacity -= state.weights[item - 1];
+            }
+
+            path.push((item - 1, capacity));
+        }
+
+        chosen.reverse();
+        let items: Vec<String> = chosen.iter().map(|item| format!("#{item}")).collect();
+        state.table.finish(
+            answer,
+            path,
+            format!("Best value {best} using items {}", items.join(", ")),
+        );
+        state.complete = true;
+    }
+}
+
+impl Algorithm for Knapsack {
+    type State = KnapsackState;
+
+    fn name(&self) -> &'static str {
+        "0/1 Knapsack"
+    }
+
+    fn short_name(&self) -> &'static str {
+        "Knapsack"
+    }
+
+    fn category(&self) -> AlgorithmCategory {
+        AlgorithmCategory::DynamicProgramming
+    }
+
+    fn info(&self) -> AlgorithmInfo {
+        AlgorithmInfo {
+            description: "0/1 knapsack: for every item and capacity, keep the better of skipping the item or taking it with the remaining capacity.",
+            best: "O(nW)",
+            average: "O(nW)",
+            worst: "O(nW)",
+            space: "O(nW)",
+            per: None,
+            stable: None,
+            in_place: None,
+            adaptive: None,
+        }
+    }
+
+    fn initial_state(&self, _bars: Vec<i32>) -> Self::State {
+        let row_labels = std::iter::once("—".to_string())
+            .chain(
+                self.weights
+                    .iter()
+                    .zip(&self.values)
+                    .enumerate()
+                    .map(|(i, (weight, value))| format!("#{} w{weight} v{value}", i + 1)),
+            )
+            .collect();
+        let col_labels = (0..=self.capacity).map(|c| c.to_string()).collect();
+
+        KnapsackState {
+            weights: self.weights.clone(),
+            values: self.values.clone(),
+            capacity: self.capacity,
+            table: DpTable::new(row_labels, col_labels),
+            next: Some((0, 0)),
+            complete: false,
+            line: None,
+        }
+    }
+
+    fn step(&self, state: &mut Self::State) -> bool {
+        if state.complete {
+            return true;
+        }
+
+        let Some((row, col)) = state.next else {
+            state.line = Some(4);
+            Self::finish(state);
+            return true;
+        };
+
+        let table = &state.table;
+        let (line, value, dependencies) = if row == 0 {
+            (1, 0, Vec::new())
+        } else {
+            let weight = state.weights[row - 1];
+            let skip = table.value(row - 1, col).unwrap_or(0);
+
+            if weight > col {
+                (2, skip, vec![(row - 1, col)])
+            } else {
+                let take = table
+                    .value(row - 1, col - weight)
+                    .unwrap_or(0)
+                    .saturating_add(state.values[row - 1]);
+                (
+                    3,
+                    skip.max(take),
+                    vec![(row - 1, col), (row - 1, col - weight)],
+                )
+            }
+        };
+
+        let note = match line {
+            1 => format!("K[0][{col}] = 0: no items to pick"),
+            2 => format!(
+                "item {row} weighs {} > {col} → K[{row}][{col}] = K[{}][{col}] = {value}",
+                state.weights[row - 1],
+                row - 1
+            ),
+            _ => format!(
+                "item {row} (w={}, v={}) → K[{row}][{col}] = max(skip, take) = {value}",
+                state.weights[row - 1],
+                state.values[row - 1]
+            ),
+        };
+        state
+            .table
+            .fill(row, col, DpCell::Value(value), dependencies, note);
+        state.next = state.table.next_cell(row, col);
+        state.line = Some(line);
+
+        false
+    }
+
+    fn visualization<'a>(&self, state: &'a Self::State) -> Visualization<'a> {
+        Visualization::Table(&state.table)
+    }
+
+    fn narration<'a>(&self, state: &'a Self::State) -> &'a str {
+        &state.table.note
+    }
+
+    fn pseudocode(&self) -> &'static [&'static str] {
+        PSEUDOCODE
+    }
+
+    fn code_line(&self, state: &Self::State) -> Option<usize> {
+        state.line
+    }
+
+    fn input(&self) -> Option<String> {
+        let weights: Vec<String> = self.weights.iter().map(usize::to_string).collect();
+        let values: Vec<String> = self.values.iter().map(i64::to_string).collect();
+
+        Some(format!(
+            "{}; {}; {}",
+            weights.join(" "),
+            values.join(" "),
+            self.capacity
+        ))
+    }
+
+    fn input_format(&self) -> &'static str {
+        "weights; values; capacity"
+    }
+
+    fn set_input(&mut self, input: &str) -> Result<(), String> {
+        let fields = split_fields(input, 3)?;
+        let weights = parse_numbers(fields[0])?;
+        let values = parse_numbers(fields[1])?;
+        let capacity = parse_numbers(fields[2])?;
+
+        if weights.is_empty() || weights.len() > MAX_ITEMS {
+            return Err(format!("enter between 1 and {MAX_ITEMS} items"));
+        }
+        if weights.len() != values.len() {
+            return Err("every item needs both a weight and a value".to_string());
+        }
+        if weights.iter().any(|&weight| weight < 1) || values.iter().any(|&value| value < 0) {
+            return Err("weights must be positive and values non-negative".to_string());
+        }
+        if values.iter().any(|&value| value > MAX_VALUE) {
+            return Err(format!("values must be at most {MAX_VALUE}"));
+        }
+        let &[capacity] = capacity.as_slice() else {
+            return Err("capacity must be a single number".to_string());
+        };
+        if !(1..=MAX_CAPACITY).contains(&capacity) {
+            return Err(format!("capacity must be between 1 and {MAX_CAPACITY}"));
+        }
+
+        self.weights = weights.into_iter().map(|weight| weight as usize).collect();
+        self.values = values;
+        self.capacity = capacity as usize;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn set_input_accepts_valid_items() {
+        let mut knapsack = Knapsack::default();
+
+        assert_eq!(knapsack.set_input("2 3; 10 20; 5"), Ok(()));
+        assert_eq!(knapsack.input().as_deref(), Some("2 3; 10 20; 5"));
+    }
+
+    #[test]
+    fn set_input_rejects_invalid_items() {
+        let mut knapsack = Knapsack::default();
+
+        assert!(knapsack.set_input("1 2; 3; 5").is_err());
+        assert!(knapsack.set_input("0 2; 3 4; 5").is_err());
+        assert!(knapsack.set_input("1 2; -3 4; 5").is_err());
+        assert!(knapsack.set_input("1 2; 3 4; 0").is_err());
+        assert!(knapsack.set_input("1 2; 3 4; 41").is_err());
+        assert!(knapsack.set_input("1 2; 3 4").is_err());
+        assert!(knapsack.set_input("1 2; 3 10000; 5").is_err());
+        assert_eq!(knapsack.input().as_deref(), Some("1 3 4 5; 1 4 5 7; 7"));
+    }
+
+    #[test]
+    fn largest_values_do_not_overflow() {
+        let mut knapsack = Knapsack::default();
+        let items = ["1"; MAX_ITEMS].join(" ");
+        let values = vec![MAX_VALUE.to_string(); MAX_ITEMS].join(" ");
+        knapsack
+            .set_input(&format!("{items}; {values}; {MAX_CAPACITY}"))
+            .unwrap();
+
+        let mut state = knapsack.initial_state(Vec::new());
+        while !knapsack.step(&mut state) {}
+
+        let best = state.table.value(MAX_ITEMS, MAX_CAPACITY as usize);
+        assert_eq!(best, Some(MAX_VALUE * MAX_ITEMS as i64));
+    }
+}
@@ -0,0 +1,224 @@
+use super::{DpCell, DpTable, char_labels};
+use crate::algorithms::input::{parse_word, split_fields};
+use crate::algorithms::{Algorithm, AlgorithmCategory, AlgorithmInfo, Visualization};
+
+const MAX_LEN: usize = 24;
+
+const PSEUDOCODE: &[&str] = &[
+    "for i in 0 ..= n, j in 0 ..= m:",
+    "  if i = 0 or j = 0: L[i][j] ← 0",
+    "  else if a[i] = b[j]: L[i][j] ← L[i-1][j-1] + 1",
+    "  else: L[i][j] ← max(L[i-1][j], L[i][j-1])",
+    "trace back from L[n][m]",
+];
+
+#[derive(Debug, Clone)]
+pub struct LcsState {
+    first: Vec<char>,
+    second: Vec<char>,
+    table: DpTable,
+    next: Option<(usize, usize)>,
+    complete: bool,
+    line: Option<usize>,
+}
+
+#[derive(Debug)]
+pub struct LongestCommonSubsequence {
+    first: String,
+    second: String,
+}
+
+impl Default for LongestCommonSubsequence {
+    fn default() -> Self {
+        Self {
+            first: "ABCBDAB".to_string(),
+            second: "BDCABA".to_string(),
+        }
+    }
+}
+
+impl LongestCommonSubsequence {
+    fn finish(state: &mut LcsState) {
+        let answer = (state.first.len(), state.second.len());
+        let length = state.table.value(answer.0, answer.1).unwrap_or(0);
+        let (mut row, mut col) = answer;
+        let mut path = vec![answer];
+        let mut subsequence = Vec::new();
+
+        while row > 0 && col > 0 {
+            if state.first[row - 1] == state.second[col - 1] {
+                subsequence.push(state.first[row - 1]);
+                row -= 1;
+                col -= 1;
+            } else if state.table.value(row - 1, col) >= state.table.value(row, col - 1) {
+                row -= 1;
+            } else {
+                col -= 1;
+            }
+
+            path.push((row, col));
+        }
+
+        let subsequence: String = subsequence.iter().rev().collect();
+        state.table.finish(
+            answer,
+            path,
+            format!("LCS length {length}: \"{subsequence}\""),
+        );
+        state.complete = true;
+    }
+}
+
+impl Algorithm for LongestCommonSubsequence {
+    type State = LcsState;
+
+    fn name(&self) -> &'static str {
+        "Longest Common Subsequence"
+    }
+
+    fn short_name(&self) -> &'static str {
+        "LCS"
+    }
+
+    fn category(&self) -> AlgorithmCategory {
+        AlgorithmCategory::DynamicProgramming
+    }
+
+    fn info(&self) -> AlgorithmInfo {
+        AlgorithmInfo {
+            description: "Fills a table of the longest common subsequence of every pair of prefixes, then traces the answer back from the corner.",
+            best: "O(nm)",
+            average: "O(nm)",
+            worst: "O(nm)",
+            space: "O(nm)",
+            per: None,
+            stable: None,
+            in_place: None,
+            adaptive: None,
+        }
+    }
+
+    fn initial_state(&self, _bars: Vec<i32>) -> Self::State {
+        let first: Vec<char> = self.first.chars().collect();
+        let second: Vec<char> = self.second.chars().collect();
+        let table = DpTable::new(char_labels(&first), char_labels(&second));
+
+        LcsState {
+            first,
+            second,
+            table,
+            next: Some((0, 0)),
+            complete: false,
+            line: None,
+        }
+    }
+
+    fn step(&self, state: &mut Self::State) -> bool {
+        if state.complete {
+            return true;
+        }
+
+        let Some((row, col)) = state.next else {
+            state.line = Some(4);
+            Self::finish(state);
+            return true;
+        };
+
+        let table = &state.table;
+        let (line, value, dependencies) = if row == 0 || col == 0 {
+            (1, 0, Vec::new())
+        } else if state.first[row - 1] == state.second[col - 1] {
+            let diagonal = table.value(row - 1, col - 1).unwrap_or(0);
+            (2, diagonal + 1, vec![(row - 1, col - 1)])
+        } else {
+            let up = table.value(row - 1, col).unwrap_or(0);
+            let left = table.value(row, col - 1).unwrap_or(0);
+            (3, up.max(left), vec![(row - 1, col), (row, col - 1)])
+        };
+
+        let note = match line {
+            1 => format!("L[{row}][{col}] = 0: one prefix is empty"),
+            2 => format!(
+                "'{}' = '{}' → L[{row}][{col}] = L[{}][{}] + 1 = {value}",
+                state.first[row - 1],
+                state.second[col - 1],
+                row - 1,
+                col - 1
+            ),
+            _ => format!(
+                "'{}' ≠ '{}' → L[{row}][{col}] = max(L[{}][{col}], L[{row}][{}]) = {value}",
+                state.first[row - 1],
+                state.second[col - 1],
+                row - 1,
+                col - 1
+            ),
+        };
+        state
+            .table
+            .fill(row, col, DpCell::Value(value), dependencies, note);
+        state.next = state.table.next_cell(row, col);
+        state.line = Some(line);
+
+        false
+    }
+
+    fn visualization<'a>(&self, state: &'a Self::State) -> Visualization<'a> {
+        Visualization::Table(&state.table)
+    }
+
+    fn narration<'a>(&self, state: &'a Self::State) -> &'a str {
+        &state.table.note
+    }
+
+    fn pseudocode(&self) -> &'static [&'static str] {
+        PSEUDOCODE
+    }
+
+    fn code_line(&self, state: &Self::State) -> Option<usize> {
+        state.line
+    }
+
+    fn input(&self) -> Option<String> {
+        Some(format!("{}; {}", self.first, self.second))
+    }
+
+    fn input_format(&self) -> &'static str {
+        "first string; second string"
+    }
+
+    fn set_input(&mut self, input: &str) -> Result<(), String> {
+        let fields = split_fields(input, 2)?;
+        let first = parse_word(fields[0], MAX_LEN)?;
+        let second = parse_word(fields[1], MAX_LEN)?;
+
+        self.first = first.into_iter().collect();
+        self.second = second.into_iter().collect();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn set_input_accepts_two_strings() {
+        let mut lcs = LongestCommonSubsequence::default();
+
+        assert_eq!(lcs.set_input(" AGGTAB ; GXTXAYB "), Ok(()));
+        assert_eq!(lcs.input().as_deref(), Some("AGGTAB; GXTXAYB"));
+    }
+
+    #[test]
+    fn set_input_rejects_invalid_strings() {
+        let mut lcs = LongestCommonSubsequence::default();
+
+        assert!(lcs.set_input("ABC").is_err());
+        assert!(lcs.set_input("ABC; ").is_err());
+        assert!(lcs.set_input("ABC; DEF; GHI").is_err());
+        let long = "A".repeat(MAX_LEN + 1);
+        assert!(lcs.set_input(&format!("{long}; ABC")).is_err());
+        assert_eq!(lcs.input().as_deref(), Some("ABCBDAB; BDCABA"));
+    }
+}
@@ -0,0 +1,206 @@
+use super::{DpCell, DpTable};
+use crate::algorithms::input::parse_numbers;
+use crate::algorithms::{Algorithm, AlgorithmCategory, AlgorithmInfo, Visualization};
+
+const MAX_LEN: usize = 30;
+const VALUE_ROW: usize = 0;
+const LENGTH_ROW: usize = 1;
+
+const PSEUDOCODE: &[&str] = &[
+    "for i in 0 .. n:",
+    "  L[i] ← 1 + max(L[j] for j < i with a[j] < a[i])",
+    "trace back from the largest L[i]",
+];
+
+#[derive(Debug, Clone)]
+pub struct LisState {
+    sequence: Vec<i64>,
+    table: DpTable,
+    next: usize,
+    complete: bool,
+    line: Option<usize>,
+}
+
+#[derive(Debug)]
+pub struct LongestIncreasingSubsequence {
+    sequence: Vec<i64>,
+}
+
+impl Default for LongestIncreasingSubsequence {
+    fn default() -> Self {
+        Self {
+            sequence: vec![10, 9, 2, 5, 3, 7, 101, 18],
+        }
+    }
+}
+
+impl LongestIncreasingSubsequence {
+    fn finish(state: &mut LisState) {
+        let lengths: Vec<i64> = (0..state.sequence.len())
+            .map(|i| state.table.value(LENGTH_ROW, i).unwrap_or(1))
+            .collect();
+        let Some((mut end, &length)) = lengths
+            .iter()
+            .enumerate()
+            .max_by_key(|&(i, len)| (len, std::cmp::Reverse(i)))
+        else {
+            state.complete = true;
+            return;
+        };
+
+        let answer = (LENGTH_ROW, end);
+        let mut picked = vec![end];
+        while lengths[end] > 1 {
+            let previous = (0..end).rev().find(|&j| {
+                state.sequence[j] < state.sequence[end] && lengths[j] == lengths[end] - 1
+            });
+
+            match previous {
+                Some(j) => {
+                    picked.push(j);
+                    end = j;
+                }
+                None => break,
+            }
+        }
+
+        picked.reverse();
+        let path = picked.iter().map(|&i| (LENGTH_ROW, i)).collect();
+        let values: Vec<String> = picked
+            .iter()
+            .map(|&i| state.sequence[i].to_string())
+            .collect();
+
+        state.table.finish(
+            answer,
+            path,
+            format!("LIS length {length}: {}", values.join(", ")),
+        );
+        state.complete = true;
+    }
+}
+
+impl Algorithm for LongestIncreasingSubsequence {
+    type State = LisState;
+
+    fn name(&self) -> &'static str {
+        "Longest Increasing Subsequence"
+    }
+
+    fn short_name(&self) -> &'static str {
+        "LIS"
+    }
+
+    fn category(&self) -> AlgorithmCategory {
+        AlgorithmCategory::DynamicProgramming
+    }
+
+    fn info(&self) -> AlgorithmInfo {
+        AlgorithmInfo {
+            description: "Longest strictly increasing subsequence: each entry extends the best earlier entry with a smaller value.",
+            best: "O(n²)",
+            average: "O(n²)",
+            worst: "O(n²)",
+            space: "O(n)",
+            per: None,
+            stable: None,
+            in_place: None,
+            adaptive: None,
+        }
+    }
+
+    fn initial_state(&self, _bars: Vec<i32>) -> Self::State {
+        let row_labels = vec!["value".to_string(), "length".to_string()];
+        let col_labels = (0..self.sequence.len()).map(|i| i.to_string()).collect();
+        let mut table = DpTable::new(row_labels, col_labels);
+
+        for (i, &value) in self.sequence.iter().enumerate() {
+            table.cells[VALUE_ROW][i] = DpCell::Value(value);
+        }
+
+        LisState {
+            sequence: self.sequence.clone(),
+            table,
+            next: 0,
+            complete: false,
+            line: None,
+        }
+    }
+
+    fn step(&self, state: &mut Self::State) -> bool {
+        if state.complete {
+            return true;
+        }
+
+        let i = state.next;
+        if i >= state.sequence.len() {
+            state.line = Some(2);
+            Self::finish(state);
+            return true;
+        }
+
+        let dependencies: Vec<(usize, usize)> = (0..i)
+            .filter(|&j| state.sequence[j] < state.sequence[i])
+            .map(|j| (LENGTH_ROW, j))
+            .collect();
+        let longest = dependencies
+            .iter()
+            .filter_map(|&(row, col)| state.table.value(row, col))
+            .max()
+            .unwrap_or(0);
+
+        let note = format!(
+            "{} smaller value(s) before a[{i}]={} → L[{i}] = {longest} + 1 = {}",
+            dependencies.len(),
+            state.sequence[i],
+            longest + 1
+        );
+        state.table.fill(
+            LENGTH_ROW,
+            i,
+            DpCell::Value(longest + 1),
+            dependencies,
+            note,
+        );
+        state.next += 1;
+        state.line = Some(1);
+
+        false
+    }
+
+    fn visualization<'a>(&self, state: &'a Self::State) -> Visualization<'a> {
+        Visualization::Table(&state.table)
+    }
+
+    fn narration<'a>(&self, state: &'a Self::State) -> &'a str {
+        &state.table.note
+    }
+
+    fn pseudocode(&self) -> &'static [&'static str] {
+        PSEUDOCODE
+    }
+
+    fn code_line(&self, state: &Self::State) -> Option<usize> {
+        state.line
+    }
+
+    fn input(&self) -> Option<String> {
+        let values: Vec<String> = self.sequence.iter().map(i64::to_string).collect();
+        Some(values.join(", "))
+    }
+
+    fn input_format(&self) -> &'static str {
+        "comma-separated numbers"
+    }
+
+    fn set_input(&mut self, input: &str) -> Result<(), String> {
+        let sequence = parse_numbers(input)?;
+        if sequence.is_empty() || sequence.len() > MAX_LEN {
+            return Err(format!("enter between 1 and {MAX_LEN} numbers"));
+        }
+
+        self.sequence = sequence;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,91 @@
+pub mod coin_change;
+pub mod edit_distance;
+pub mod knapsack;
+pub mod lcs;
+pub mod lis;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DpCell {
+    Empty,
+    Value(i64),
+    Infinite,
+}
+
+#[derive(Debug, Clone)]
+pub struct DpTable {
+    pub row_labels: Vec<String>,
+    pub col_labels: Vec<String>,
+    pub cells: Vec<Vec<DpCell>>,
+    pub current: Option<(usize, usize)>,
+    pub dependencies: Vec<(usize, usize)>,
+    pub result: Option<String>,
+    pub note: String,
+}
+
+impl DpTable {
+    pub fn new(row_labels: Vec<String>, col_labels: Vec<String>) -> Self {
+        let cells = vec![vec![DpCell::Empty; col_labels.len()]; row_labels.len()];
+
+        Self {
+            row_labels,
+            col_labels,
+            cells,
+            current: None,
+            dependencies: Vec::new(),
+            result: None,
+            note: String::new(),
+        }
+    }
+
+    pub fn rows(&self) -> usize {
+        self.row_labels.len()
+    }
+
+    pub fn cols(&self) -> usize {
+        self.col_labels.len()
+    }
+
+    pub fn value(&self, row: usize, col: usize) -> Option<i64> {
+        match self.cells[row][col] {
+            DpCell::Value(value) => Some(value),
+            DpCell::Empty | DpCell::Infinite => None,
+        }
+    }
+
+    fn fill(
+        &mut self,
+        row: usize,
+        col: usize,
+        cell: DpCell,
+        dependencies: Vec<(usize, usize)>,
+        note: String,
+    ) {
+        self.cells[row][col] = cell;
+        self.current = Some((row, col));
+        self.dependencies = dependencies;
+        self.note = note;
+    }
+
+    fn finish(&mut self, answer: (usize, usize), path: Vec<(usize, usize)>, result: String) {
+        self.current = Some(answer);
+        self.dependencies = path;
+        self.note = result.clone();
+        self.result = Some(result);
+    }
+
+    fn next_cell(&self, row: usize, col: usize) -> Option<(usize, usize)> {
+        if col + 1 < self.cols() {
+            Some((row, col + 1))
+        } else if row + 1 < self.rows() {
+            Some((row + 1, 0))
+        } else {
+            None
+        }
+    }
+}
+
+fn char_labels(chars: &[char]) -> Vec<String> {
+    std::iter::once("ε".to_string())
+        .chain(chars.iter().map(char::to_string))
+        .collect()
+}
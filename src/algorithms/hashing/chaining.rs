@@ -0,0 +1,181 @@
+use super::{HashTableView, INITIAL_CAPACITY, hash, next_prime};
+use crate::algorithms::{Algorithm, AlgorithmCategory, AlgorithmInfo, Visualization};
+
+const MAX_LOAD: f64 = 1.0;
+
+const PSEUDOCODE: &[&str] = &[
+    "insert(k):",
+    "  if (n + 1) / m > 1.0: grow m and rehash",
+    "  b ← h(k) = k mod m",
+    "  for each key in bucket b:",
+    "    if key = k: return",
+    "  append k to bucket b",
+];
+
+#[derive(Debug, Clone)]
+pub struct ChainingState {
+    view: HashTableView,
+    keys: Vec<i32>,
+    next_key: usize,
+    chain_position: Option<usize>,
+    complete: bool,
+    line: Option<usize>,
+}
+
+#[derive(Debug)]
+pub struct SeparateChaining;
+
+impl SeparateChaining {
+    fn rehash(state: &mut ChainingState) {
+        let keys = state.view.keys();
+        let capacity = next_prime(state.view.tables[0].len() * 2);
+        state.view.clear_tables(capacity);
+        state.view.stats.resizes += 1;
+
+        for key in keys {
+            let bucket = hash(key, capacity);
+            state.view.tables[0][bucket].push(key);
+            state.view.loads[0][bucket] += 1;
+        }
+    }
+}
+
+impl Algorithm for SeparateChaining {
+    type State = ChainingState;
+
+    fn name(&self) -> &'static str {
+        "Separate Chaining"
+    }
+
+    fn short_name(&self) -> &'static str {
+        "Chaining"
+    }
+
+    fn category(&self) -> AlgorithmCategory {
+        AlgorithmCategory::Hashing
+    }
+
+    fn info(&self) -> AlgorithmInfo {
+        AlgorithmInfo {
+            description: "Each slot holds a list of keys; collisions are appended to the bucket's chain.",
+            best: "O(1)",
+            average: "O(1)",
+            worst: "O(n)",
+            space: "O(m + n)",
+            per: Some("insert"),
+            stable: None,
+            in_place: None,
+            adaptive: None,
+        }
+    }
+
+    fn initial_state(&self, bars: Vec<i32>) -> Self::State {
+        let mut view = HashTableView::new(1, INITIAL_CAPACITY);
+        view.note = format!(
+            "Insert {} keys, starting with {INITIAL_CAPACITY} buckets",
+            bars.len()
+        );
+
+        ChainingState {
+            view,
+            keys: bars,
+            next_key: 0,
+            chain_position: None,
+            complete: false,
+            line: None,
+        }
+    }
+
+    fn step(&self, state: &mut Self::State) -> bool {
+        if state.complete {
+            return true;
+        }
+
+        let Some(position) = state.chain_position else {
+            let Some(&key) = state.keys.get(state.next_key) else {
+                state.view.finish();
+                state.complete = true;
+                state.line = None;
+                return true;
+            };
+
+            let capacity = state.view.tables[0].len();
+            let projected = (state.view.stats.inserted + 1) as f64 / capacity as f64;
+            if projected > MAX_LOAD {
+                Self::rehash(state);
+                state.view.probe = None;
+                state.view.path.clear();
+                state.view.note = format!(
+                    "Load factor would exceed {MAX_LOAD:.2}: resize {capacity} → {} and rehash",
+                    state.view.tables[0].len()
+                );
+                state.line = Some(1);
+                return false;
+            }
+
+            let bucket = hash(key, capacity);
+            state.view.begin_insert(key);
+            state.view.visit(0, bucket);
+            state.view.note = if state.view.tables[0][bucket].is_empty() {
+                format!("h({key}) = {bucket}: bucket is empty")
+            } else {
+                state.view.stats.collisions += 1;
+                format!("h({key}) = {bucket}: collision, walk the chain")
+            };
+            state.chain_position = Some(0);
+            state.line = Some(2);
+            return false;
+        };
+
+        let Some(key) = state.view.key else {
+            return false;
+        };
+        let bucket = hash(key, state.view.tables[0].len());
+        let chain = &state.view.tables[0][bucket];
+
+        match chain.get(position).copied() {
+            Some(existing) if existing == key => {
+                state.view.note = format!("{key} is already in bucket {bucket}");
+                state.line = Some(4);
+                state.chain_position = None;
+                state.next_key += 1;
+            }
+            Some(existing) => {
+                state.view.stats.probes += 1;
+                state.view.note = format!("Compare with {existing} at chain position {position}");
+                state.chain_position = Some(position + 1);
+                state.line = Some(3);
+            }
+            None => {
+                state.view.tables[0][bucket].push(key);
+                state.view.loads[0][bucket] += 1;
+                state.view.record_insert(position + 1);
+                state.view.note = format!(
+                    "Append {key} to bucket {bucket} (chain length {})",
+                    position + 1
+                );
+                state.chain_position = None;
+                state.next_key += 1;
+                state.line = Some(5);
+            }
+        }
+
+        false
+    }
+
+    fn visualization<'a>(&self, state: &'a Self::State) -> Visualization<'a> {
+        Visualization::HashTable(&state.view)
+    }
+
+    fn narration<'a>(&self, state: &'a Self::State) -> &'a str {
+        &state.view.note
+    }
+
+    fn pseudocode(&self) -> &'static [&'static str] {
+        PSEUDOCODE
+    }
+
+    fn code_line(&self, state: &Self::State) -> Option<usize> {
+        state.line
+    }
+}
@@ -0,0 +1,252 @@
+use super::{HashTableView, INITIAL_CAPACITY, next_prime};
+use crate::algorithms::{Algorithm, AlgorithmCategory, AlgorithmInfo, Visualization};
+
+const MAX_LOAD: f64 = 0.5;
+const MAX_KICKS: usize = 16;
+
+const PSEUDOCODE: &[&str] = &[
+    "insert(k):",
+    "  if (n + 1) / 2m > 0.5: grow m and rehash",
+    "  t ← 1; repeat up to 16 times:",
+    "    s ← h_t(k)",
+    "    if T_t[s] is empty: T_t[s] ← k; return",
+    "    swap k with T_t[s]; t ← other table",
+    "  grow m and rehash everything",
+];
+
+#[derive(Debug, Clone)]
+pub struct CuckooState {
+    view: HashTableView,
+    keys: Vec<i32>,
+    next_key: usize,
+    homeless: Option<i32>,
+    table: usize,
+    kicks: usize,
+    complete: bool,
+    line: Option<usize>,
+}
+
+#[derive(Debug)]
+pub struct CuckooHashing;
+
+impl CuckooHashing {
+    fn slot(key: i32, table: usize, capacity: usize) -> usize {
+        let capacity = capacity as i32;
+        let hashed = if table == 0 {
+            key
+        } else {
+            key.div_euclid(capacity)
+        };
+
+        hashed.rem_euclid(capacity) as usize
+    }
+
+    fn place_all(view: &mut HashTableView, keys: &[i32]) -> bool {
+        let capacity = view.tables[0].len();
+
+        for &key in keys {
+            let mut homeless = key;
+            let mut table = 0;
+            let mut placed = false;
+
+            for _ in 0..=MAX_KICKS {
+                let slot = Self::slot(homeless, table, capacity);
+                view.loads[table][slot] = 1;
+
+                match view.tables[table][slot].pop() {
+                    None => {
+                        view.tables[table][slot].push(homeless);
+                        placed = true;
+                        break;
+                    }
+                    Some(evicted) => {
+                        view.tables[table][slot].push(homeless);
+                        homeless = evicted;
+                        table = 1 - table;
+                    }
+                }
+            }
+
+            if !placed {
+                return false;
+            }
+        }
+
+        true
+    }
+
+    fn rehash(state: &mut CuckooState) {
+        let mut keys = state.view.keys();
+        keys.extend(state.homeless.take());
+        let mut capacity = next_prime(state.view.tables[0].len() * 2);
+        state.view.stats.resizes += 1;
+
+        loop {
+            state.view.clear_tables(capacity);
+            if Self::place_all(&mut state.view, &keys) {
+                return;
+            }
+
+            capacity = next_prime(capacity * 2);
+        }
+    }
+}
+
+impl Algorithm for CuckooHashing {
+    type State = CuckooState;
+
+    fn name(&self) -> &'static str {
+        "Cuckoo Hashing"
+    }
+
+    fn short_name(&self) -> &'static str {
+        "Cuckoo"
+    }
+
+    fn category(&self) -> AlgorithmCategory {
+        AlgorithmCategory::Hashing
+    }
+
+    fn info(&self) -> AlgorithmInfo {
+        AlgorithmInfo {
+            description: "Two tables with two hash functions; an insert evicts the occupant to its other table until everything has a home or the tables grow.",
+            best: "O(1)",
+            average: "O(1) amortized",
+            worst: "O(n)",
+            space: "O(m)",
+            per: Some("insert"),
+            stable: None,
+            in_place: None,
+            adaptive: None,
+        }
+    }
+
+    fn initial_state(&self, bars: Vec<i32>) -> Self::State {
+        let mut view = HashTableView::new(2, INITIAL_CAPACITY);
+        view.note = format!(
+            "Insert {} keys into two tables of {INITIAL_CAPACITY} slots",
+            bars.len()
+        );
+
+        CuckooState {
+            view,
+            keys: bars,
+            next_key: 0,
+            homeless: None,
+            table: 0,
+            kicks: 0,
+            complete: false,
+            line: None,
+        }
+    }
+
+    fn step(&self, state: &mut Self::State) -> bool {
+        if state.complete {
+            return true;
+        }
+
+        let capacity = state.view.tables[0].len();
+
+        let key = match state.homeless {
+            Some(key) => key,
+            None => {
+                let Some(&key) = state.keys.get(state.next_key) else {
+                    state.view.finish();
+                    state.complete = true;
+                    state.line = None;
+                    return true;
+                };
+
+                let projected = (state.view.stats.inserted + 1) as f64 / (capacity * 2) as f64;
+                if projected > MAX_LOAD {
+                    Self::rehash(state);
+                    state.view.probe = None;
+                    state.view.path.clear();
+                    state.view.note = format!(
+                        "Load factor would exceed {MAX_LOAD:.2}: resize {capacity} → {} per table and rehash",
+                        state.view.tables[0].len()
+                    );
+                    state.line = Some(1);
+                    return false;
+                }
+
+                state.view.begin_insert(key);
+                state.homeless = Some(key);
+                state.table = 0;
+                state.kicks = 0;
+                key
+            }
+        };
+
+        let table = state.table;
+        let slot = Self::slot(key, table, capacity);
+        state.view.visit(table, slot);
+
+        match state.view.tables[table][slot].first().copied() {
+            None => {
+                state.view.tables[table][slot].push(key);
+                state.view.loads[table][slot] = 1;
+                state.view.record_insert(state.kicks + 1);
+                state.view.note = format!(
+                    "h{}({key}) = {slot} is free → store {key} in table {}",
+                    table + 1,
+                    table + 1
+                );
+                state.homeless = None;
+                state.next_key += 1;
+                state.line = Some(4);
+            }
+            Some(existing) if existing == key => {
+                state.view.note = format!("{key} is already in table {}", table + 1);
+                state.line = Some(4);
+                state.homeless = None;
+                state.next_key += 1;
+            }
+            Some(evicted) => {
+                state.view.tables[table][slot] = vec![key];
+                state.view.stats.collisions += 1;
+                state.kicks += 1;
+                state.homeless = Some(evicted);
+                state.table = 1 - table;
+                state.line = Some(5);
+                state.view.note = format!(
+                    "h{}({key}) = {slot} holds {evicted} → evict it to table {}",
+                    table + 1,
+                    2 - table
+                );
+
+                if state.kicks > MAX_KICKS {
+                    let old = capacity;
+                    Self::rehash(state);
+                    state.view.record_insert(state.kicks);
+                    state.view.probe = None;
+                    state.view.path.clear();
+                    state.view.note = format!(
+                        "Eviction cycle after {MAX_KICKS} kicks: resize {old} → {} per table and rehash",
+                        state.view.tables[0].len()
+                    );
+                    state.next_key += 1;
+                    state.line = Some(6);
+                }
+            }
+        }
+
+        false
+    }
+
+    fn visualization<'a>(&self, state: &'a Self::State) -> Visualization<'a> {
+        Visualization::HashTable(&state.view)
+    }
+
+    fn narration<'a>(&self, state: &'a Self::State) -> &'a str {
+        &state.view.note
+    }
+
+    fn pseudocode(&self) -> &'static [&'static str] {
+        PSEUDOCODE
+    }
+
+    fn code_line(&self, state: &Self::State) -> Option<usize> {
+        state.line
+    }
+}
@@ -0,0 +1,59 @@
+use super::{OpenAddressingState, Probing};
+use crate::algorithms::{Algorithm, AlgorithmCategory, AlgorithmInfo, Visualization};
+
+#[derive(Debug)]
+pub struct DoubleHashing;
+
+impl Algorithm for DoubleHashing {
+    type State = OpenAddressingState;
+
+    fn name(&self) -> &'static str {
+        "Double Hashing"
+    }
+
+    fn short_name(&self) -> &'static str {
+        "Double"
+    }
+
+    fn category(&self) -> AlgorithmCategory {
+        AlgorithmCategory::Hashing
+    }
+
+    fn info(&self) -> AlgorithmInfo {
+        AlgorithmInfo {
+            description: "Open addressing whose probe step comes from a second hash, giving each key its own probe sequence.",
+            best: "O(1)",
+            average: "O(1)",
+            worst: "O(n)",
+            space: "O(m)",
+            per: Some("insert"),
+            stable: None,
+            in_place: None,
+            adaptive: None,
+        }
+    }
+
+    fn initial_state(&self, bars: Vec<i32>) -> Self::State {
+        OpenAddressingState::new(bars)
+    }
+
+    fn step(&self, state: &mut Self::State) -> bool {
+        state.step(Probing::Double)
+    }
+
+    fn visualization<'a>(&self, state: &'a Self::State) -> Visualization<'a> {
+        Visualization::HashTable(&state.view)
+    }
+
+    fn narration<'a>(&self, state: &'a Self::State) -> &'a str {
+        &state.view.note
+    }
+
+    fn pseudocode(&self) -> &'static [&'static str] {
+        Probing::Double.pseudocode()
+    }
+
+    fn code_line(&self, state: &Self::State) -> Option<usize> {
+        state.line
+    }
+}
@@ -0,0 +1,59 @@
+use super::{OpenAddressingState, Probing};
+use crate::algorithms::{Algorithm, AlgorithmCategory, AlgorithmInfo, Visualization};
+
+#[derive(Debug)]
+pub struct LinearProbing;
+
+impl Algorithm for LinearProbing {
+    type State = OpenAddressingState;
+
+    fn name(&self) -> &'static str {
+        "Linear Probing"
+    }
+
+    fn short_name(&self) -> &'static str {
+        "Linear"
+    }
+
+    fn category(&self) -> AlgorithmCategory {
+        AlgorithmCategory::Hashing
+    }
+
+    fn info(&self) -> AlgorithmInfo {
+        AlgorithmInfo {
+            description: "Open addressing that probes the next slot (h, h+1, h+2, …) until it finds a free one; suffers from primary clustering.",
+            best: "O(1)",
+            average: "O(1)",
+            worst: "O(n)",
+            space: "O(m)",
+            per: Some("insert"),
+            stable: None,
+            in_place: None,
+            adaptive: None,
+        }
+    }
+
+    fn initial_state(&self, bars: Vec<i32>) -> Self::State {
+        OpenAddressingState::new(bars)
+    }
+
+    fn step(&self, state: &mut Self::State) -> bool {
+        state.step(Probing::Linear)
+    }
+
+    fn visualization<'a>(&self, state: &'a Self::State) -> Visualization<'a> {
+        Visualization::HashTable(&state.view)
+    }
+
+    fn narration<'a>(&self, state: &'a Self::State) -> &'a str {
+        &state.view.note
+    }
+
+    fn pseudocode(&self) -> &'static [&'static str] {
+        Probing::Linear.pseudocode()
+    }
+
+    fn code_line(&self, state: &Self::State) -> Option<usize> {
+        state.line
+    }
+}
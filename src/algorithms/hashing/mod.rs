@@ -0,0 +1,341 @@
+pub mod chaining;
+pub mod cuckoo;
+pub mod double;
+pub mod linear;
+pub mod quadratic;
+
+const INITIAL_CAPACITY: usize = 11;
+
+const LINEAR_PSEUDOCODE: &[&str] = &[
+    "insert(k):",
+    "  if (n + 1) / m > 0.7: grow m and rehash",
+    "  for i in 0, 1, 2, …:",
+    "    s ← (h(k) + i) mod m",
+    "    if T[s] is empty: T[s] ← k; return",
+    "    if T[s] = k: return",
+    "    collision: try the next i",
+];
+
+const QUADRATIC_PSEUDOCODE: &[&str] = &[
+    "insert(k):",
+    "  if (n + 1) / m > 0.5: grow m and rehash",
+    "  for i in 0, 1, 2, …:",
+    "    s ← (h(k) + i²) mod m",
+    "    if T[s] is empty: T[s] ← k; return",
+    "    if T[s] = k: return",
+    "    collision: try the next i",
+];
+
+const DOUBLE_PSEUDOCODE: &[&str] = &[
+    "insert(k):",
+    "  if (n + 1) / m > 0.7: grow m and rehash",
+    "  for i in 0, 1, 2, …:",
+    "    s ← (h(k) + i · h₂(k)) mod m",
+    "    if T[s] is empty: T[s] ← k; return",
+    "    if T[s] = k: return",
+    "    collision: try the next i",
+];
+
+#[derive(Debug, Clone, Default)]
+pub struct HashStats {
+    pub inserted: usize,
+    pub capacity: usize,
+    pub probes: usize,
+    pub longest_probe: usize,
+    pub collisions: usize,
+    pub resizes: usize,
+}
+
+impl HashStats {
+    pub fn load_factor(&self) -> f64 {
+        if self.capacity == 0 {
+            0.0
+        } else {
+            self.inserted as f64 / self.capacity as f64
+        }
+    }
+
+    pub fn average_probe(&self) -> f64 {
+        if self.inserted == 0 {
+            0.0
+        } else {
+            self.probes as f64 / self.inserted as f64
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct HashTableView {
+    pub tables: Vec<Vec<Vec<i32>>>,
+    pub loads: Vec<Vec<u64>>,
+    pub key: Option<i32>,
+    pub probe: Option<(usize, usize)>,
+    pub path: Vec<(usize, usize)>,
+    pub stats: HashStats,
+    pub note: String,
+}
+
+impl HashTableView {
+    fn new(table_count: usize, capacity: usize) -> Self {
+        Self {
+            tables: vec![vec![Vec::new(); capacity]; table_count],
+            loads: vec![vec![0; capacity]; table_count],
+            key: None,
+            probe: None,
+            path: Vec::new(),
+            stats: HashStats {
+                capacity: capacity * table_count,
+                ..HashStats::default()
+            },
+            note: String::new(),
+        }
+    }
+
+    fn keys(&self) -> Vec<i32> {
+        self.tables.iter().flatten().flatten().copied().collect()
+    }
+
+    fn clear_tables(&mut self, capacity: usize) {
+        let table_count = self.tables.len();
+        self.tables = vec![vec![Vec::new(); capacity]; table_count];
+        self.loads = vec![vec![0; capacity]; table_count];
+        self.stats.capacity = capacity * table_count;
+    }
+
+    fn begin_insert(&mut self, key: i32) {
+        self.key = Some(key);
+        self.probe = None;
+        self.path.clear();
+    }
+
+    fn visit(&mut self, table: usize, slot: usize) {
+        self.probe = Some((table, slot));
+        self.path.push((table, slot));
+        self.stats.probes += 1;
+    }
+
+    fn record_insert(&mut self, probes: usize) {
+        self.stats.inserted += 1;
+        self.stats.longest_probe = self.stats.longest_probe.max(probes);
+    }
+
+    fn finish(&mut self) {
+        self.key = None;
+        self.probe = None;
+        self.path.clear();
+        self.note = format!(
+            "Inserted {} keys · load factor {:.2} · {} resize(s)",
+            self.stats.inserted,
+            self.stats.load_factor(),
+            self.stats.resizes
+        );
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Probing {
+    Linear,
+    Quadratic,
+    Double,
+}
+
+impl Probing {
+    fn max_load(self) -> f64 {
+        match self {
+            Probing::Linear | Probing::Double => 0.7,
+            Probing::Quadratic => 0.5,
+        }
+    }
+
+    fn slot(self, key: i32, attempt: usize, capacity: usize) -> usize {
+        let home = hash(key, capacity);
+        let offset = match self {
+            Probing::Linear => attempt,
+            Probing::Quadratic => attempt * attempt,
+            Probing::Double => attempt * step_hash(key, capacity),
+        };
+
+        (home + offset) % capacity
+    }
+
+    fn pseudocode(self) -> &'static [&'static str] {
+        match self {
+            Probing::Linear => LINEAR_PSEUDOCODE,
+            Probing::Quadratic => QUADRATIC_PSEUDOCODE,
+            Probing::Double => DOUBLE_PSEUDOCODE,
+        }
+    }
+
+    fn formula(self) -> &'static str {
+        match self {
+            Probing::Linear => "h(k) + i",
+            Probing::Quadratic => "h(k) + i²",
+            Probing::Double => "h(k) + i·h₂(k)",
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct OpenAddressingState {
+    view: HashTableView,
+    keys: Vec<i32>,
+    next_key: usize,
+    attempt: usize,
+    active: bool,
+    complete: bool,
+    line: Option<usize>,
+}
+
+impl OpenAddressingState {
+    fn new(keys: Vec<i32>) -> Self {
+        let mut view = HashTableView::new(1, INITIAL_CAPACITY);
+        view.note = format!(
+            "Insert {} keys, starting with {INITIAL_CAPACITY} slots",
+            keys.len()
+        );
+
+        Self {
+            view,
+            keys,
+            next_key: 0,
+            attempt: 0,
+            active: false,
+            complete: false,
+            line: None,
+        }
+    }
+
+    fn capacity(&self) -> usize {
+        self.view.tables[0].len()
+    }
+
+    fn rehash(&mut self, probing: Probing) {
+        let keys = self.view.keys();
+        let mut capacity = next_prime(self.capacity() * 2);
+        self.view.stats.resizes += 1;
+
+        'grow: loop {
+            self.view.clear_tables(capacity);
+
+            for &key in &keys {
+                let free = (0..capacity)
+                    .map(|attempt| (attempt, probing.slot(key, attempt, capacity)))
+                    .find(|&(_, slot)| self.view.tables[0][slot].is_empty());
+
+                let Some((attempt, slot)) = free else {
+                    capacity = next_prime(capacity * 2);
+                    continue 'grow;
+                };
+
+                self.view.tables[0][slot].push(key);
+                self.view.loads[0][slot] = attempt as u64 + 1;
+            }
+
+            return;
+        }
+    }
+
+    fn step(&mut self, probing: Probing) -> bool {
+        if self.complete {
+            return true;
+        }
+
+        if !self.active {
+            let Some(&key) = self.keys.get(self.next_key) else {
+                self.view.finish();
+                self.complete = true;
+                self.line = None;
+                return true;
+            };
+
+            let projected = (self.view.stats.inserted + 1) as f64 / self.capacity() as f64;
+            if projected > probing.max_load() {
+                let old = self.capacity();
+                self.rehash(probing);
+                self.view.probe = None;
+                self.view.path.clear();
+                self.view.note = format!(
+                    "Load factor would exceed {:.2}: resize {old} → {} and rehash",
+                    probing.max_load(),
+                    self.capacity()
+                );
+                self.line = Some(1);
+                return false;
+            }
+
+            self.view.begin_insert(key);
+            self.attempt = 0;
+            self.active = true;
+        }
+
+        let Some(key) = self.view.key else {
+            return false;
+        };
+        let capacity = self.capacity();
+        let slot = probing.slot(key, self.attempt, capacity);
+        self.view.visit(0, slot);
+
+        match self.view.tables[0][slot].first().copied() {
+            None => {
+                let probes = self.attempt + 1;
+                self.view.tables[0][slot].push(key);
+                self.view.loads[0][slot] = probes as u64;
+                self.view.record_insert(probes);
+                self.view.note = format!(
+                    "Slot {} = {slot} is empty → store {key} after {probes} probe(s)",
+                    probing.formula()
+                );
+                self.active = false;
+                self.next_key += 1;
+                self.line = Some(4);
+            }
+            Some(existing) if existing == key => {
+                self.view.note = format!("{key} is already stored in slot {slot}");
+                self.line = Some(5);
+                self.active = false;
+                self.next_key += 1;
+            }
+            Some(existing) => {
+                self.view.stats.collisions += 1;
+                self.attempt += 1;
+                self.view.note = format!(
+                    "Slot {slot} holds {existing} → collision, probe i = {}",
+                    self.attempt
+                );
+                self.line = Some(6);
+
+                if self.attempt >= capacity {
+                    let old = capacity;
+                    self.rehash(probing);
+                    self.view.begin_insert(key);
+                    self.attempt = 0;
+                    self.view.note = format!(
+                        "No free slot reachable for {key}: resize {old} → {} and rehash",
+                        self.capacity()
+                    );
+                    self.line = Some(1);
+                }
+            }
+        }
+
+        false
+    }
+}
+
+fn hash(key: i32, capacity: usize) -> usize {
+    key.rem_euclid(capacity as i32) as usize
+}
+
+fn step_hash(key: i32, capacity: usize) -> usize {
+    1 + key.rem_euclid(capacity as i32 - 1) as usize
+}
+
+fn next_prime(from: usize) -> usize {
+    (from.max(2)..)
+        .find(|&candidate| {
+            (2..)
+                .take_while(|d| d * d <= candidate)
+                .all(|d| candidate % d != 0)
+        })
+        .unwrap_or(from)
+}
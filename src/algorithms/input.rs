@@ -0,0 +1,47 @@
+pub fn split_fields(input: &str, expected: usize) -> Result<Vec<&str>, String> {
+    let fields: Vec<&str> = input.split(';').map(str::trim).collect();
+    if fields.len() != expected {
+        return Err(format!(
+            "expected {expected} fields separated by ';', got {}",
+            fields.len()
+        ));
+    }
+
+    Ok(fields)
+}
+
+pub fn parse_numbers(field: &str) -> Result<Vec<i64>, String> {
+    field
+        .split(|c: char| c == ',' || c.is_whitespace())
+        .filter(|token| !token.is_empty())
+        .map(|token| {
+            token
+                .parse::<i64>()
+                .map_err(|_| format!("'{token}' is not a whole number"))
+        })
+        .collect()
+}
+
+pub fn parse_bars(input: &str, max_len: usize) -> Result<Vec<i32>, String> {
+    let numbers = parse_numbers(input)?;
+    if numbers.is_empty() || numbers.len() > max_len {
+        return Err(format!("enter between 1 and {max_len} numbers"));
+    }
+
+    numbers
+        .into_iter()
+        .map(|number| i32::try_from(number).map_err(|_| format!("{number} is out of range")))
+        .collect()
+}
+
+pub fn parse_word(field: &str, max_len: usize) -> Result<Vec<char>, String> {
+    let chars: Vec<char> = field.chars().collect();
+    if chars.is_empty() {
+        return Err("strings must not be empty".to_string());
+    }
+    if chars.len() > max_len {
+        return Err(format!("strings are limited to {max_len} characters"));
+    }
+
+    Ok(chars)
+}
@@ -0,0 +1,240 @@
+use super::{LookupTable, MatchInput, TextMatch, TextRow};
+use crate::algorithms::{Algorithm, AlgorithmCategory, AlgorithmInfo, Visualization};
+
+const PSEUDOCODE: &[&str] = &[
+    "precompute bad-char and good-suffix shifts",
+    "s ← 0",
+    "while s ≤ n - m:",
+    "  j ← m - 1",
+    "  while j ≥ 0 and p[j] = text[s + j]: j ← j - 1",
+    "  if j < 0: report match at s; s ← s + gs[0]",
+    "  else: s ← s + max(bad char shift, gs[j + 1])",
+];
+
+#[derive(Debug, Clone)]
+pub struct BoyerMooreState {
+    view: TextMatch,
+    last_occurrence: Vec<(char, usize)>,
+    good_suffix: Vec<usize>,
+    shift: usize,
+    j: usize,
+    complete: bool,
+    line: Option<usize>,
+}
+
+#[derive(Debug, Default)]
+pub struct BoyerMoore {
+    input: MatchInput,
+}
+
+impl BoyerMoore {
+    fn last_occurrence(pattern: &[char]) -> Vec<(char, usize)> {
+        let mut table: Vec<(char, usize)> = Vec::new();
+
+        for (index, &c) in pattern.iter().enumerate() {
+            match table.iter_mut().find(|(existing, _)| *existing == c) {
+                Some(entry) => entry.1 = index,
+                None => table.push((c, index)),
+            }
+        }
+
+        table
+    }
+
+    fn good_suffix(pattern: &[char]) -> Vec<usize> {
+        let m = pattern.len();
+        let mut shift = vec![0; m + 1];
+        let mut border = vec![0; m + 1];
+        let (mut i, mut j) = (m, m + 1);
+        border[i] = j;
+
+        while i > 0 {
+            while j <= m && pattern[i - 1] != pattern[j - 1] {
+                if shift[j] == 0 {
+                    shift[j] = j - i;
+                }
+                j = border[j];
+            }
+
+            i -= 1;
+            j -= 1;
+            border[i] = j;
+        }
+
+        j = border[0];
+        for (i, entry) in shift.iter_mut().enumerate() {
+            if *entry == 0 {
+                *entry = j;
+            }
+            if i == j {
+                j = border[j];
+            }
+        }
+
+        shift
+    }
+
+    fn update_tables(
+        state: &mut BoyerMooreState,
+        bad_char: Option<char>,
+        good_suffix: Option<usize>,
+    ) {
+        let m = state.view.pattern.len();
+        state.view.rows = vec![TextRow {
+            label: "good sfx".to_string(),
+            cells: (0..m)
+                .map(|k| state.good_suffix[k + 1].to_string())
+                .collect(),
+            offset: state.view.alignment,
+            highlight: good_suffix,
+        }];
+        state.view.tables = vec![LookupTable {
+            label: "bad char".to_string(),
+            headers: state
+                .last_occurrence
+                .iter()
+                .map(|(c, _)| c.to_string())
+                .collect(),
+            values: state
+                .last_occurrence
+                .iter()
+                .map(|(_, index)| index.to_string())
+                .collect(),
+            highlight: bad_char
+                .and_then(|bad| state.last_occurrence.iter().position(|&(c, _)| c == bad)),
+        }];
+    }
+}
+
+impl Algorithm for BoyerMoore {
+    type State = BoyerMooreState;
+
+    fn name(&self) -> &'static str {
+        "Boyer–Moore"
+    }
+
+    fn short_name(&self) -> &'static str {
+        "BM"
+    }
+
+    fn category(&self) -> AlgorithmCategory {
+        AlgorithmCategory::StringMatching
+    }
+
+    fn info(&self) -> AlgorithmInfo {
+        AlgorithmInfo {
+            description: "Compares right to left and skips ahead using the bad-character and good-suffix rules.",
+            best: "O(n/m)",
+            average: "O(n/m)",
+            worst: "O(nm)",
+            space: "O(m + σ)",
+            per: None,
+            stable: None,
+            in_place: None,
+            adaptive: None,
+        }
+    }
+
+    fn initial_state(&self, _bars: Vec<i32>) -> Self::State {
+        let view = self.input.view();
+        let m = view.pattern.len();
+        let mut state = BoyerMooreState {
+            last_occurrence: Self::last_occurrence(&view.pattern),
+            good_suffix: Self::good_suffix(&view.pattern),
+            view,
+            shift: 0,
+            j: m - 1,
+            complete: false,
+            line: None,
+        };
+
+        state.view.note = "Compare right to left".to_string();
+        Self::update_tables(&mut state, None, None);
+        state
+    }
+
+    fn step(&self, state: &mut Self::State) -> bool {
+        if state.complete {
+            return true;
+        }
+
+        let n = state.view.text.len();
+        let m = state.view.pattern.len();
+        if state.shift + m > n {
+            state.view.finish();
+            Self::update_tables(state, None, None);
+            state.complete = true;
+            state.line = None;
+            return true;
+        }
+
+        let (shift, j) = (state.shift, state.j);
+        state.view.confirmed = (j + 1)..m;
+
+        if state.view.compare(shift, j) {
+            if j == 0 {
+                let next = state.good_suffix[0];
+                state.view.matches.push(shift);
+                state.view.confirmed = 0..m;
+                state.view.note = format!("Match at {shift}, good suffix shift {next}");
+                state.line = Some(5);
+                state.shift += next;
+                state.j = m - 1;
+            } else {
+                state.view.note = format!("text[{}] = pattern[{j}], move left", shift + j);
+                state.line = Some(4);
+                state.j -= 1;
+            }
+
+            Self::update_tables(state, None, None);
+            return false;
+        }
+
+        let bad = state.view.text[shift + j];
+        let bad_char_shift = match state.last_occurrence.iter().find(|&&(c, _)| c == bad) {
+            Some(&(_, index)) if index < j => j - index,
+            Some(_) => 1,
+            None => j + 1,
+        };
+        let good_suffix_shift = state.good_suffix[j + 1];
+        let next = bad_char_shift.max(good_suffix_shift);
+
+        state.view.note = format!(
+            "Mismatch on '{bad}': bad char shift {bad_char_shift}, good suffix shift {good_suffix_shift} → shift {next}"
+        );
+        Self::update_tables(state, Some(bad), Some(j));
+        state.line = Some(6);
+        state.shift += next;
+        state.j = m - 1;
+
+        false
+    }
+
+    fn visualization<'a>(&self, state: &'a Self::State) -> Visualization<'a> {
+        Visualization::Text(&state.view)
+    }
+
+    fn narration<'a>(&self, state: &'a Self::State) -> &'a str {
+        &state.view.note
+    }
+
+    fn pseudocode(&self) -> &'static [&'static str] {
+        PSEUDOCODE
+    }
+
+    fn code_line(&self, state: &Self::State) -> Option<usize> {
+        state.line
+    }
+
+    fn input(&self) -> Option<String> {
+        Some(self.input.to_input())
+    }
+
+    fn input_format(&self) -> &'static str {
+        "text; pattern"
+    }
+
+    fn set_input(&mut self, input: &str) -> Result<(), String> {
+        self.input.parse(input)
+    }
+}
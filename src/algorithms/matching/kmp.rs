@@ -0,0 +1,229 @@
+use super::{MatchInput, TextMatch, TextRow};
+use crate::algorithms::{Algorithm, AlgorithmCategory, AlgorithmInfo, Visualization};
+
+const PSEUDOCODE: &[&str] = &[
+    "build failure table, i over pattern:",
+    "  if p[i] = p[len]: len ← len + 1; fail[i] ← len",
+    "  else if len > 0: len ← fail[len - 1]",
+    "  else: fail[i] ← 0",
+    "search, i over text, j over pattern:",
+    "  if text[i] = p[j]: i ← i + 1; j ← j + 1",
+    "    if j = m: report match; j ← fail[m - 1]",
+    "  else if j > 0: j ← fail[j - 1]",
+    "  else: i ← i + 1",
+];
+
+#[derive(Debug, Clone)]
+pub struct KmpState {
+    view: TextMatch,
+    failure: Vec<usize>,
+    built: usize,
+    prefix_len: usize,
+    i: usize,
+    j: usize,
+    complete: bool,
+    line: Option<usize>,
+}
+
+#[derive(Debug, Default)]
+pub struct Kmp {
+    input: MatchInput,
+}
+
+impl Kmp {
+    fn update_failure_row(state: &mut KmpState, highlight: Option<usize>) {
+        let cells = (0..state.failure.len())
+            .map(|k| {
+                if k < state.built {
+                    state.failure[k].to_string()
+                } else {
+                    "·".to_string()
+                }
+            })
+            .collect();
+
+        state.view.rows = vec![TextRow {
+            label: "failure".to_string(),
+            cells,
+            offset: state.view.alignment,
+            highlight,
+        }];
+    }
+
+    fn build_step(state: &mut KmpState) {
+        let i = state.built;
+        let len = state.prefix_len;
+        let pattern = &state.view.pattern;
+        state.view.compared = None;
+
+        if pattern[i] == pattern[len] {
+            state.prefix_len += 1;
+            state.failure[i] = state.prefix_len;
+            state.built += 1;
+            state.line = Some(1);
+            state.view.note = format!(
+                "pattern[{i}] = pattern[{len}] → failure[{i}] = {}",
+                state.prefix_len
+            );
+            Self::update_failure_row(state, Some(i));
+        } else if len > 0 {
+            state.prefix_len = state.failure[len - 1];
+            state.line = Some(2);
+            state.view.note = format!(
+                "pattern[{i}] ≠ pattern[{len}] → fall back to failure[{}] = {}",
+                len - 1,
+                state.prefix_len
+            );
+            Self::update_failure_row(state, Some(len - 1));
+        } else {
+            state.failure[i] = 0;
+            state.built += 1;
+            state.line = Some(3);
+            state.view.note = format!("pattern[{i}] ≠ pattern[0] → failure[{i}] = 0");
+            Self::update_failure_row(state, Some(i));
+        }
+    }
+
+    fn search_step(state: &mut KmpState) {
+        let m = state.view.pattern.len();
+        let (i, j) = (state.i, state.j);
+        state.view.confirmed = 0..j;
+
+        if state.view.compare(i - j, j) {
+            state.i += 1;
+            state.j += 1;
+
+            if state.j == m {
+                state.view.matches.push(i + 1 - m);
+                state.view.confirmed = 0..m;
+                state.j = state.failure[m - 1];
+                state.line = Some(6);
+                state.view.note = format!(
+                    "Match at {}, keep failure[{}] = {} characters",
+                    i + 1 - m,
+                    m - 1,
+                    state.j
+                );
+                Self::update_failure_row(state, Some(m - 1));
+                return;
+            }
+
+            state.view.note = format!("text[{i}] = pattern[{j}], compare next");
+            state.line = Some(5);
+            Self::update_failure_row(state, None);
+        } else if j > 0 {
+            state.j = state.failure[j - 1];
+            state.line = Some(7);
+            state.view.note = format!(
+                "Mismatch at text[{i}], keep failure[{}] = {} characters",
+                j - 1,
+                state.j
+            );
+            Self::update_failure_row(state, Some(j - 1));
+        } else {
+            state.i += 1;
+            state.line = Some(8);
+            state.view.note = format!("Mismatch at text[{i}] with pattern[0], advance text");
+            Self::update_failure_row(state, None);
+        }
+    }
+}
+
+impl Algorithm for Kmp {
+    type State = KmpState;
+
+    fn name(&self) -> &'static str {
+        "Knuth–Morris–Pratt"
+    }
+
+    fn short_name(&self) -> &'static str {
+        "KMP"
+    }
+
+    fn category(&self) -> AlgorithmCategory {
+        AlgorithmCategory::StringMatching
+    }
+
+    fn info(&self) -> AlgorithmInfo {
+        AlgorithmInfo {
+            description: "Knuth–Morris–Pratt: a failure table of pattern borders lets the scan resume after a mismatch without re-reading text.",
+            best: "O(n + m)",
+            average: "O(n + m)",
+            worst: "O(n + m)",
+            space: "O(m)",
+            per: None,
+            stable: None,
+            in_place: None,
+            adaptive: None,
+        }
+    }
+
+    fn initial_state(&self, _bars: Vec<i32>) -> Self::State {
+        let view = self.input.view();
+        let m = view.pattern.len();
+        let mut state = KmpState {
+            view,
+            failure: vec![0; m],
+            built: 1,
+            prefix_len: 0,
+            i: 0,
+            j: 0,
+            complete: false,
+            line: None,
+        };
+
+        state.view.note = "Build the failure table".to_string();
+        Self::update_failure_row(&mut state, Some(0));
+        state
+    }
+
+    fn step(&self, state: &mut Self::State) -> bool {
+        if state.complete {
+            return true;
+        }
+
+        if state.built < state.view.pattern.len() {
+            Self::build_step(state);
+            return false;
+        }
+
+        if state.i >= state.view.text.len() {
+            state.view.finish();
+            Self::update_failure_row(state, None);
+            state.complete = true;
+            state.line = None;
+            return true;
+        }
+
+        Self::search_step(state);
+        false
+    }
+
+    fn visualization<'a>(&self, state: &'a Self::State) -> Visualization<'a> {
+        Visualization::Text(&state.view)
+    }
+
+    fn narration<'a>(&self, state: &'a Self::State) -> &'a str {
+        &state.view.note
+    }
+
+    fn pseudocode(&self) -> &'static [&'static str] {
+        PSEUDOCODE
+    }
+
+    fn code_line(&self, state: &Self::State) -> Option<usize> {
+        state.line
+    }
+
+    fn input(&self) -> Option<String> {
+        Some(self.input.to_input())
+    }
+
+    fn input_format(&self) -> &'static str {
+        "text; pattern"
+    }
+
+    fn set_input(&mut self, input: &str) -> Result<(), String> {
+        self.input.parse(input)
+    }
+}
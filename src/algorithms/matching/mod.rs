@@ -0,0 +1,116 @@
+use std::ops::Range;
+
+use crate::algorithms::input::{parse_word, split_fields};
+
+pub mod boyer_moore;
+pub mod kmp;
+pub mod naive;
+pub mod rabin_karp;
+pub mod z_algorithm;
+
+const MAX_TEXT_LEN: usize = 60;
+const DEFAULT_TEXT: &str = "ABABDABACDABABCABAB";
+const DEFAULT_PATTERN: &str = "ABABCABAB";
+
+#[derive(Debug, Clone)]
+pub struct TextRow {
+    pub label: String,
+    pub cells: Vec<String>,
+    pub offset: usize,
+    pub highlight: Option<usize>,
+}
+
+#[derive(Debug, Clone)]
+pub struct LookupTable {
+    pub label: String,
+    pub headers: Vec<String>,
+    pub values: Vec<String>,
+    pub highlight: Option<usize>,
+}
+
+#[derive(Debug, Clone)]
+pub struct TextMatch {
+    pub text: Vec<char>,
+    pub pattern: Vec<char>,
+    pub alignment: usize,
+    pub compared: Option<(usize, bool)>,
+    pub confirmed: Range<usize>,
+    pub matches: Vec<usize>,
+    pub rows: Vec<TextRow>,
+    pub tables: Vec<LookupTable>,
+    pub note: String,
+}
+
+impl TextMatch {
+    fn new(text: Vec<char>, pattern: Vec<char>) -> Self {
+        Self {
+            text,
+            pattern,
+            alignment: 0,
+            compared: None,
+            confirmed: 0..0,
+            matches: Vec::new(),
+            rows: Vec::new(),
+            tables: Vec::new(),
+            note: String::new(),
+        }
+    }
+
+    fn compare(&mut self, alignment: usize, index: usize) -> bool {
+        let matched = self.text[alignment + index] == self.pattern[index];
+        self.alignment = alignment;
+        self.compared = Some((index, matched));
+        matched
+    }
+
+    fn finish(&mut self) {
+        self.compared = None;
+        self.confirmed = 0..0;
+        if let Some(&last) = self.matches.last() {
+            self.alignment = last;
+            self.confirmed = 0..self.pattern.len();
+        }
+        self.note = match self.matches.len() {
+            0 => "Pattern not found".to_string(),
+            1 => "Found 1 occurrence".to_string(),
+            count => format!("Found {count} occurrences"),
+        };
+    }
+}
+
+#[derive(Debug)]
+pub struct MatchInput {
+    text: String,
+    pattern: String,
+}
+
+impl Default for MatchInput {
+    fn default() -> Self {
+        Self {
+            text: DEFAULT_TEXT.to_string(),
+            pattern: DEFAULT_PATTERN.to_string(),
+        }
+    }
+}
+
+impl MatchInput {
+    fn view(&self) -> TextMatch {
+        TextMatch::new(self.text.chars().collect(), self.pattern.chars().collect())
+    }
+
+    fn to_input(&self) -> String {
+        format!("{}; {}", self.text, self.pattern)
+    }
+
+    fn parse(&mut self, input: &str) -> Result<(), String> {
+        let fields = split_fields(input, 2)?;
+        let text = parse_word(fields[0], MAX_TEXT_LEN)?;
+        let pattern = parse_word(fields[1], text.len())
+            .map_err(|_| "the pattern must be non-empty and no longer than the text".to_string())?;
+
+        self.text = text.into_iter().collect();
+        self.pattern = pattern.into_iter().collect();
+
+        Ok(())
+    }
+}
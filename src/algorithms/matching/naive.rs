@@ -0,0 +1,133 @@
+use super::{MatchInput, TextMatch};
+use crate::algorithms::{Algorithm, AlgorithmCategory, AlgorithmInfo, Visualization};
+
+const PSEUDOCODE: &[&str] = &[
+    "for s in 0 ..= n - m:",
+    "  j ← 0",
+    "  while j < m and text[s + j] = pattern[j]:",
+    "    j ← j + 1",
+    "  if j = m: report match at s",
+];
+
+#[derive(Debug, Clone)]
+pub struct NaiveSearchState {
+    view: TextMatch,
+    shift: usize,
+    j: usize,
+    complete: bool,
+    line: Option<usize>,
+}
+
+#[derive(Debug, Default)]
+pub struct NaiveSearch {
+    input: MatchInput,
+}
+
+impl Algorithm for NaiveSearch {
+    type State = NaiveSearchState;
+
+    fn name(&self) -> &'static str {
+        "Naive Search"
+    }
+
+    fn short_name(&self) -> &'static str {
+        "Naive"
+    }
+
+    fn category(&self) -> AlgorithmCategory {
+        AlgorithmCategory::StringMatching
+    }
+
+    fn info(&self) -> AlgorithmInfo {
+        AlgorithmInfo {
+            description: "Tries every alignment of the pattern against the text and compares character by character.",
+            best: "O(n)",
+            average: "O(n)",
+            worst: "O(nm)",
+            space: "O(1)",
+            per: None,
+            stable: None,
+            in_place: None,
+            adaptive: None,
+        }
+    }
+
+    fn initial_state(&self, _bars: Vec<i32>) -> Self::State {
+        NaiveSearchState {
+            view: self.input.view(),
+            shift: 0,
+            j: 0,
+            complete: false,
+            line: None,
+        }
+    }
+
+    fn step(&self, state: &mut Self::State) -> bool {
+        if state.complete {
+            return true;
+        }
+
+        let n = state.view.text.len();
+        let m = state.view.pattern.len();
+        if state.shift + m > n {
+            state.view.finish();
+            state.line = None;
+            state.complete = true;
+            return true;
+        }
+
+        let (shift, j) = (state.shift, state.j);
+        state.view.confirmed = 0..j;
+
+        if state.view.compare(shift, j) {
+            state.j += 1;
+
+            if state.j == m {
+                state.view.matches.push(shift);
+                state.view.confirmed = 0..m;
+                state.view.note = format!("Match at {shift}, shift by 1");
+                state.line = Some(4);
+                state.shift += 1;
+                state.j = 0;
+            } else {
+                state.view.note = format!("text[{}] = pattern[{j}], compare next", shift + j);
+                state.line = Some(3);
+            }
+        } else {
+            state.view.note = format!("Mismatch at text[{}], shift by 1", shift + j);
+            state.line = Some(2);
+            state.shift += 1;
+            state.j = 0;
+        }
+
+        false
+    }
+
+    fn visualization<'a>(&self, state: &'a Self::State) -> Visualization<'a> {
+        Visualization::Text(&state.view)
+    }
+
+    fn narration<'a>(&self, state: &'a Self::State) -> &'a str {
+        &state.view.note
+    }
+
+    fn pseudocode(&self) -> &'static [&'static str] {
+        PSEUDOCODE
+    }
+
+    fn code_line(&self, state: &Self::State) -> Option<usize> {
+        state.line
+    }
+
+    fn input(&self) -> Option<String> {
+        Some(self.input.to_input())
+    }
+
+    fn input_format(&self) -> &'static str {
+        "text; pattern"
+    }
+
+    fn set_input(&mut self, input: &str) -> Result<(), String> {
+        self.input.parse(input)
+    }
+}
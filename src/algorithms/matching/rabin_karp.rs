@@ -0,0 +1,221 @@
+use super::{LookupTable, MatchInput, TextMatch, TextRow};
+use crate::algorithms::{Algorithm, AlgorithmCategory, AlgorithmInfo, Visualization};
+
+const BASE: u64 = 256;
+const MODULUS: u64 = 101;
+
+const PSEUDOCODE: &[&str] = &[
+    "hp ← hash(pattern); ht ← hash(text[0 .. m])",
+    "for s in 0 ..= n - m:",
+    "  if ht = hp:",
+    "    compare text[s .. s + m] with pattern",
+    "    if all characters match: report match at s",
+    "  ht ← roll(ht, text[s], text[s + m])",
+];
+
+#[derive(Debug, Clone)]
+pub struct RabinKarpState {
+    view: TextMatch,
+    pattern_hash: u64,
+    window_hash: u64,
+    high_order: u64,
+    hashes: Vec<Option<u64>>,
+    shift: usize,
+    verifying: Option<usize>,
+    complete: bool,
+    line: Option<usize>,
+}
+
+#[derive(Debug, Default)]
+pub struct RabinKarp {
+    input: MatchInput,
+}
+
+impl RabinKarp {
+    fn hash(chars: &[char]) -> u64 {
+        chars
+            .iter()
+            .fold(0, |hash, &c| (hash * BASE + c as u64) % MODULUS)
+    }
+
+    fn roll(state: &mut RabinKarpState) {
+        let m = state.view.pattern.len();
+        let s = state.shift;
+
+        if s + m < state.view.text.len() {
+            let outgoing = state.view.text[s] as u64 * state.high_order % MODULUS;
+            let incoming = state.view.text[s + m] as u64;
+            state.window_hash =
+                ((state.window_hash + MODULUS - outgoing) * BASE + incoming) % MODULUS;
+        }
+
+        state.shift += 1;
+        state.verifying = None;
+    }
+
+    fn update_rows(state: &mut RabinKarpState, highlight: Option<usize>) {
+        state.view.rows = vec![TextRow {
+            label: "hash".to_string(),
+            cells: state
+                .hashes
+                .iter()
+                .map(|hash| hash.map_or("·".to_string(), |hash| hash.to_string()))
+                .collect(),
+            offset: 0,
+            highlight,
+        }];
+        state.view.tables = vec![LookupTable {
+            label: format!("mod {MODULUS}"),
+            headers: vec!["pattern".to_string(), "window".to_string()],
+            values: vec![
+                state.pattern_hash.to_string(),
+                state.window_hash.to_string(),
+            ],
+            highlight: highlight.map(|_| 1),
+        }];
+    }
+}
+
+impl Algorithm for RabinKarp {
+    type State = RabinKarpState;
+
+    fn name(&self) -> &'static str {
+        "Rabin–Karp"
+    }
+
+    fn short_name(&self) -> &'static str {
+        "RK"
+    }
+
+    fn category(&self) -> AlgorithmCategory {
+        AlgorithmCategory::StringMatching
+    }
+
+    fn info(&self) -> AlgorithmInfo {
+        AlgorithmInfo {
+            description: "Compares rolling hashes of each text window with the pattern hash and verifies characters only when they match.",
+            best: "O(n + m)",
+            average: "O(n + m)",
+            worst: "O(nm)",
+            space: "O(1)",
+            per: None,
+            stable: None,
+            in_place: None,
+            adaptive: None,
+        }
+    }
+
+    fn initial_state(&self, _bars: Vec<i32>) -> Self::State {
+        let view = self.input.view();
+        let m = view.pattern.len();
+        let n = view.text.len();
+        let high_order = (1..m).fold(1, |power, _| power * BASE % MODULUS);
+
+        let mut state = RabinKarpState {
+            pattern_hash: Self::hash(&view.pattern),
+            window_hash: Self::hash(&view.text[..m]),
+            high_order,
+            hashes: vec![None; n - m + 1],
+            view,
+            shift: 0,
+            verifying: None,
+            complete: false,
+            line: None,
+        };
+
+        state.view.note = format!("Rolling hash with base {BASE}, modulus {MODULUS}");
+        Self::update_rows(&mut state, None);
+        state
+    }
+
+    fn step(&self, state: &mut Self::State) -> bool {
+        if state.complete {
+            return true;
+        }
+
+        let m = state.view.pattern.len();
+        let s = state.shift;
+        if s + m > state.view.text.len() {
+            state.view.finish();
+            Self::update_rows(state, None);
+            state.complete = true;
+            state.line = None;
+            return true;
+        }
+
+        let Some(j) = state.verifying else {
+            state.hashes[s] = Some(state.window_hash);
+            state.view.alignment = s;
+            state.view.compared = None;
+            state.view.confirmed = 0..0;
+            Self::update_rows(state, Some(s));
+
+            if state.window_hash == state.pattern_hash {
+                state.view.note = format!(
+                    "Window hash {} = pattern hash, verify characters",
+                    state.window_hash
+                );
+                state.verifying = Some(0);
+                state.line = Some(2);
+            } else {
+                state.view.note = format!(
+                    "Window hash {} ≠ pattern hash {}, roll to next window",
+                    state.window_hash, state.pattern_hash
+                );
+                Self::roll(state);
+                state.line = Some(5);
+            }
+
+            return false;
+        };
+
+        state.view.confirmed = 0..j;
+        Self::update_rows(state, Some(s));
+
+        if !state.view.compare(s, j) {
+            state.view.note = format!("Spurious hit at {s}: text[{}] ≠ pattern[{j}]", s + j);
+            state.line = Some(3);
+            Self::roll(state);
+        } else if j + 1 == m {
+            state.view.matches.push(s);
+            state.view.confirmed = 0..m;
+            state.view.note = format!("Verified match at {s}");
+            state.line = Some(4);
+            Self::roll(state);
+        } else {
+            state.view.note = format!("text[{}] = pattern[{j}], keep verifying", s + j);
+            state.line = Some(3);
+            state.verifying = Some(j + 1);
+        }
+
+        false
+    }
+
+    fn visualization<'a>(&self, state: &'a Self::State) -> Visualization<'a> {
+        Visualization::Text(&state.view)
+    }
+
+    fn narration<'a>(&self, state: &'a Self::State) -> &'a str {
+        &state.view.note
+    }
+
+    fn pseudocode(&self) -> &'static [&'static str] {
+        PSEUDOCODE
+    }
+
+    fn code_line(&self, state: &Self::State) -> Option<usize> {
+        state.line
+    }
+
+    fn input(&self) -> Option<String> {
+        Some(self.input.to_input())
+    }
+
+    fn input_format(&self) -> &'static str {
+        "text; pattern"
+    }
+
+    fn set_input(&mut self, input: &str) -> Result<(), String> {
+        self.input.parse(input)
+    }
+}
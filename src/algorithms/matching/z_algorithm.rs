@@ -0,0 +1,258 @@
+use super::{MatchInput, TextMatch, TextRow};
+use crate::algorithms::{Algorithm, AlgorithmCategory, AlgorithmInfo, Visualization};
+
+const SEPARATOR: char = '$';
+
+const PSEUDOCODE: &[&str] = &[
+    "s ← pattern + \"$\" + text; l ← 0; r ← 0",
+    "for i in 1 .. |s|:",
+    "  if i < r and Z[i - l] < r - i: Z[i] ← Z[i - l]",
+    "  else: k ← max(0, r - i)",
+    "    while s[i + k] = s[k]: k ← k + 1",
+    "    Z[i] ← k; if i + k > r: l ← i; r ← i + k",
+    "  if Z[i] = m: report match at i - m - 1",
+];
+
+#[derive(Debug, Clone)]
+pub struct ZAlgorithmState {
+    view: TextMatch,
+    z: Vec<Option<usize>>,
+    left: usize,
+    right: usize,
+    i: usize,
+    extending: Option<usize>,
+    complete: bool,
+    line: Option<usize>,
+}
+
+#[derive(Debug, Default)]
+pub struct ZAlgorithm {
+    input: MatchInput,
+}
+
+impl ZAlgorithm {
+    fn update_rows(state: &mut ZAlgorithmState, highlight: Option<usize>) {
+        let z_cells = state
+            .z
+            .iter()
+            .enumerate()
+            .map(|(index, value)| match value {
+                _ if index == 0 => "—".to_string(),
+                Some(value) => value.to_string(),
+                None => "·".to_string(),
+            })
+            .collect();
+        let box_cells = (0..state.z.len())
+            .map(|index| {
+                if (state.left..state.right).contains(&index) {
+                    "▔".to_string()
+                } else {
+                    String::new()
+                }
+            })
+            .collect();
+
+        state.view.rows = vec![
+            TextRow {
+                label: "Z".to_string(),
+                cells: z_cells,
+                offset: 0,
+                highlight,
+            },
+            TextRow {
+                label: "Z-box".to_string(),
+                cells: box_cells,
+                offset: 0,
+                highlight: None,
+            },
+        ];
+    }
+
+    fn finalize(state: &mut ZAlgorithmState, length: usize, line: usize) {
+        let i = state.i;
+        let m = state.view.pattern.len();
+        state.z[i] = Some(length);
+
+        if i + length > state.right {
+            state.left = i;
+            state.right = i + length;
+        }
+
+        state.line = Some(line);
+        if length == m && i > m {
+            state.view.matches.push(i);
+            state.view.confirmed = 0..m;
+            state.line = Some(6);
+        }
+
+        Self::update_rows(state, Some(i));
+        state.i += 1;
+        state.extending = None;
+    }
+}
+
+impl Algorithm for ZAlgorithm {
+    type State = ZAlgorithmState;
+
+    fn name(&self) -> &'static str {
+        "Z-Algorithm"
+    }
+
+    fn short_name(&self) -> &'static str {
+        "Z"
+    }
+
+    fn category(&self) -> AlgorithmCategory {
+        AlgorithmCategory::StringMatching
+    }
+
+    fn info(&self) -> AlgorithmInfo {
+        AlgorithmInfo {
+            description: "Builds the Z-array of pattern + separator + text; positions whose Z-value equals the pattern length are matches.",
+            best: "O(n + m)",
+            average: "O(n + m)",
+            worst: "O(n + m)",
+            space: "O(n + m)",
+            per: None,
+            stable: None,
+            in_place: None,
+            adaptive: None,
+        }
+    }
+
+    fn initial_state(&self, _bars: Vec<i32>) -> Self::State {
+        let input = self.input.view();
+        let combined: Vec<char> = input
+            .pattern
+            .iter()
+            .copied()
+            .chain(std::iter::once(SEPARATOR))
+            .chain(input.text.iter().copied())
+            .collect();
+        let len = combined.len();
+
+        let mut state = ZAlgorithmState {
+            view: TextMatch::new(combined, input.pattern),
+            z: vec![None; len],
+            left: 0,
+            right: 0,
+            i: 1,
+            extending: None,
+            complete: false,
+            line: None,
+        };
+
+        state.view.note = format!("Compute Z over pattern {SEPARATOR} text");
+        Self::update_rows(&mut state, None);
+        state
+    }
+
+    fn step(&self, state: &mut Self::State) -> bool {
+        if state.complete {
+            return true;
+        }
+
+        let len = state.view.text.len();
+        let m = state.view.pattern.len();
+        let i = state.i;
+
+        if i >= len {
+            let positions: Vec<String> = state
+                .view
+                .matches
+                .iter()
+                .map(|&index| (index - m - 1).to_string())
+                .collect();
+            state.view.finish();
+            if !positions.is_empty() {
+                state.view.note = format!(
+                    "{} at text positions {}",
+                    state.view.note,
+                    positions.join(", ")
+                );
+            }
+            Self::update_rows(state, None);
+            state.complete = true;
+            state.line = None;
+            return true;
+        }
+
+        let Some(length) = state.extending else {
+            state.view.alignment = i;
+            state.view.compared = None;
+
+            if i < state.right {
+                let mirrored = state.z[i - state.left].unwrap_or(0);
+                let remaining = state.right - i;
+
+                if mirrored < remaining {
+                    state.view.confirmed = 0..mirrored;
+                    state.view.note =
+                        format!("Inside Z-box: Z[{i}] = Z[{}] = {mirrored}", i - state.left);
+                    Self::finalize(state, mirrored, 2);
+                    return false;
+                }
+
+                state.view.confirmed = 0..remaining;
+                state.view.note =
+                    format!("Inside Z-box: {remaining} characters known, extend past it");
+                state.extending = Some(remaining);
+                state.line = Some(3);
+            } else {
+                state.view.confirmed = 0..0;
+                state.view.note = format!("Position {i} outside Z-box: compare from scratch");
+                state.extending = Some(0);
+                state.line = Some(3);
+            }
+
+            Self::update_rows(state, Some(i));
+            return false;
+        };
+
+        state.view.confirmed = 0..length;
+
+        if length >= m || i + length >= len {
+            state.view.compared = None;
+            state.view.note = format!("Z[{i}] = {length}");
+            Self::finalize(state, length, 5);
+        } else if state.view.compare(i, length) {
+            state.view.note = format!("s[{}] = s[{length}], extend", i + length);
+            state.extending = Some(length + 1);
+            state.line = Some(4);
+            Self::update_rows(state, Some(i));
+        } else {
+            state.view.note = format!("s[{}] ≠ s[{length}] → Z[{i}] = {length}", i + length);
+            Self::finalize(state, length, 5);
+        }
+
+        false
+    }
+
+    fn visualization<'a>(&self, state: &'a Self::State) -> Visualization<'a> {
+        Visualization::Text(&state.view)
+    }
+
+    fn narration<'a>(&self, state: &'a Self::State) -> &'a str {
+        &state.view.note
+    }
+
+    fn pseudocode(&self) -> &'static [&'static str] {
+        PSEUDOCODE
+    }
+
+    fn code_line(&self, state: &Self::State) -> Option<usize> {
+        state.line
+    }
+
+    fn input(&self) -> Option<String> {
+        Some(self.input.to_input())
+    }
+
+    fn input_format(&self) -> &'static str {
+        "text; pattern"
+    }
+
+    fn set_input(&mut self, input: &str) -> Result<(), String> {
+        self.input.parse(input)
+    }
+}
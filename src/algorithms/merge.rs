@@ -1,9 +1,21 @@
-use super::Algorithm;
+use super::{
+    Algorithm, AlgorithmCategory, AlgorithmInfo, ArrayView, Element, StepEvent, Visualization,
+};
+
+const PSEUDOCODE: &[&str] = &[
+    "for width in 1, 2, 4, … while width < n:",
+    "  for each run pair [lo, mid) [mid, hi):",
+    "    copy a[lo .. hi] into aux",
+    "    while both runs have elements:",
+    "      if aux[l] <= aux[r]: a[k++] ← aux[l++]",
+    "      else: a[k++] ← aux[r++]",
+    "    copy the remaining run into a",
+];
 
 #[derive(Debug, Clone)]
 pub struct MergeSortState {
-    bars: Vec<i32>,
-    aux: Vec<i32>,
+    bars: Vec<Element>,
+    aux: Vec<Element>,
     width: usize,
     pair_start: usize,
     left_end: usize,
@@ -13,8 +25,11 @@ pub struct MergeSortState {
     write_idx: usize,
     in_merge: bool,
     complete: bool,
-    comparisons: Vec<(usize, usize)>,
+    events: Vec<StepEvent>,
+    finalized: Vec<bool>,
     current_indices: Vec<usize>,
+    line: Option<usize>,
+    note: String,
 }
 
 #[derive(Debug)]
@@ -29,22 +44,37 @@ impl MergeSort {
         state.left_end = left_end;
         state.right_end = right_end;
         state.aux[start..right_end].copy_from_slice(&state.bars[start..right_end]);
+        state.events.push(StepEvent::RangeEntered {
+            start,
+            end: right_end,
+        });
+        state.events.extend((start..right_end).map(StepEvent::Read));
         state.left_idx = start;
         state.right_idx = left_end;
         state.write_idx = start;
         state.in_merge = true;
+        state.line = Some(2);
+        state.note =
+            format!("merge runs [{start}..{left_end}) and [{left_end}..{right_end}) via aux");
     }
 
     fn advance_pair(state: &mut MergeSortState) {
         state.in_merge = false;
         state.pair_start += state.width * 2;
+        state.line = Some(1);
 
         if state.pair_start >= state.bars.len() {
             state.width *= 2;
             state.pair_start = 0;
+            state.line = Some(0);
+            state.note = format!("all runs of width {} merged", state.width / 2);
+            state.events.push(StepEvent::PassComplete);
 
             if state.width >= state.bars.len() {
                 state.complete = true;
+                state.line = None;
+                state.finalized.fill(true);
+                state.events.push(StepEvent::Done);
             }
         }
     }
@@ -57,11 +87,34 @@ impl Algorithm for MergeSort {
         "Merge Sort"
     }
 
+    fn short_name(&self) -> &'static str {
+        "Merge"
+    }
+
+    fn category(&self) -> AlgorithmCategory {
+        AlgorithmCategory::Sorting
+    }
+
+    fn info(&self) -> AlgorithmInfo {
+        AlgorithmInfo {
+            description: "Bottom-up merge sort: merges sorted runs of width 1, 2, 4, … into a buffer until the whole array is one run.",
+            best: "O(n log n)",
+            average: "O(n log n)",
+            worst: "O(n log n)",
+            space: "O(n)",
+            per: None,
+            stable: Some(true),
+            in_place: Some(false),
+            adaptive: Some(false),
+        }
+    }
+
     fn initial_state(&self, bars: Vec<i32>) -> Self::State {
         let n = bars.len();
         MergeSortState {
-            aux: vec![0; n],
-            bars,
+            aux: vec![Element::default(); n],
+            finalized: vec![n <= 1; n],
+            bars: Element::tag_all(bars),
             width: 1,
             pair_start: 0,
             left_end: 0,
@@ -71,8 +124,10 @@ impl Algorithm for MergeSort {
             write_idx: 0,
             in_merge: false,
             complete: n <= 1,
-            comparisons: Vec::new(),
+            events: Vec::new(),
             current_indices: Vec::new(),
+            line: None,
+            note: String::new(),
         }
     }
 
@@ -82,32 +137,54 @@ impl Algorithm for MergeSort {
         }
 
         let n = state.bars.len();
-        state.comparisons.clear();
+        state.events.clear();
         state.current_indices.clear();
 
         if state.in_merge {
             if state.left_idx < state.left_end && state.right_idx < state.right_end {
-                state.comparisons
-                    .push((state.left_idx, state.right_idx));
+                state
+                    .events
+                    .push(StepEvent::Compare(state.left_idx, state.right_idx));
                 state.current_indices = vec![state.left_idx, state.right_idx, state.write_idx];
 
-                if state.aux[state.left_idx] <= state.aux[state.right_idx] {
-                    state.bars[state.write_idx] = state.aux[state.left_idx];
+                let (l, r, k) = (state.left_idx, state.right_idx, state.write_idx);
+                let (left, right) = (state.aux[l].value, state.aux[r].value);
+                if left <= right {
+                    state.bars[k] = state.aux[l];
                     state.left_idx += 1;
+                    state.line = Some(4);
+                    state.note =
+                        format!("aux[{l}]={left} <= aux[{r}]={right} → write {left} to bars[{k}]");
                 } else {
-                    state.bars[state.write_idx] = state.aux[state.right_idx];
+                    state.bars[k] = state.aux[r];
                     state.right_idx += 1;
+                    state.line = Some(5);
+                    state.note =
+                        format!("aux[{l}]={left} > aux[{r}]={right} → write {right} to bars[{k}]");
                 }
 
+                state.events.push(StepEvent::Write(k));
                 state.write_idx += 1;
             } else if state.left_idx < state.left_end {
                 state.current_indices = vec![state.left_idx, state.write_idx];
+                state.line = Some(6);
+                state.note = format!(
+                    "copy remaining aux[{}]={} to bars[{}]",
+                    state.left_idx, state.aux[state.left_idx].value, state.write_idx
+                );
                 state.bars[state.write_idx] = state.aux[state.left_idx];
+                state.events.push(StepEvent::Write(state.write_idx));
                 state.left_idx += 1;
                 state.write_idx += 1;
             } else if state.right_idx < state.right_end {
                 state.current_indices = vec![state.right_idx, state.write_idx];
+                state.line = Some(6);
+                state.note = format!(
+                    "copy remaining aux[{}]={} to bars[{}]",
+                    state.right_idx, state.aux[state.right_idx].value, state.write_idx
+                );
                 state.bars[state.write_idx] = state.aux[state.right_idx];
+                state.events.push(StepEvent::Write(state.write_idx));
                 state.right_idx += 1;
                 state.write_idx += 1;
             } else {
@@ -119,6 +196,9 @@ impl Algorithm for MergeSort {
 
         if state.width >= n {
             state.complete = true;
+            state.line = None;
+            state.finalized.fill(true);
+            state.events.push(StepEvent::Done);
             return true;
         }
 
@@ -134,15 +214,33 @@ impl Algorithm for MergeSort {
         false
     }
 
-    fn get_data(&self, state: &Self::State) -> Vec<i32> {
-        state.bars.clone()
+    fn visualization<'a>(&self, state: &'a Self::State) -> Visualization<'a> {
+        Visualization::Array(ArrayView {
+            elements: &state.bars,
+            current: &state.current_indices,
+            events: &state.events,
+            pivot: None,
+            finalized: &state.finalized,
+            active: state
+                .in_merge
+                .then_some((state.pair_start, state.right_end)),
+            sorted_run: (!state.complete && state.width > 1).then_some(state.width),
+        })
+    }
+
+    fn narration<'a>(&self, state: &'a Self::State) -> &'a str {
+        &state.note
+    }
+
+    fn events<'a>(&self, state: &'a Self::State) -> &'a [StepEvent] {
+        &state.events
     }
 
-    fn get_comparisons(&self, state: &Self::State) -> Vec<(usize, usize)> {
-        state.comparisons.clone()
+    fn pseudocode(&self) -> &'static [&'static str] {
+        PSEUDOCODE
     }
 
-    fn get_current_indices(&self, state: &Self::State) -> Vec<usize> {
-        state.current_indices.clone()
+    fn code_line(&self, state: &Self::State) -> Option<usize> {
+        state.line
     }
 }
@@ -1,26 +1,394 @@
+use std::any::Any;
 use std::fmt::Debug;
+use std::ops::ControlFlow;
+use std::time::Instant;
 
 use crate::algorithms::{
     bubble::{BubbleSort, BubbleSortState},
+    dp::{
+        DpTable,
+        coin_change::{CoinChange, CoinChangeState},
+        edit_distance::{EditDistance, EditDistanceState},
+        knapsack::{Knapsack, KnapsackState},
+        lcs::{LcsState, LongestCommonSubsequence},
+        lis::{LisState, LongestIncreasingSubsequence},
+    },
+    hashing::{
+        HashTableView, OpenAddressingState,
+        chaining::{ChainingState, SeparateChaining},
+        cuckoo::{CuckooHashing, CuckooState},
+        double::DoubleHashing,
+        linear::LinearProbing,
+        quadratic::QuadraticProbing,
+    },
+    matching::{
+        TextMatch,
+        boyer_moore::{BoyerMoore, BoyerMooreState},
+        kmp::{Kmp, KmpState},
+        naive::{NaiveSearch, NaiveSearchState},
+        rabin_karp::{RabinKarp, RabinKarpState},
+        z_algorithm::{ZAlgorithm, ZAlgorithmState},
+    },
     merge::{MergeSort, MergeSortState},
     quick::{QuickSort, QuickSortState},
     selection::{SelectionSort, SelectionSortState},
 };
 
 pub mod bubble;
+pub mod dp;
+pub mod hashing;
+pub mod input;
+pub mod matching;
 pub mod merge;
 pub mod quick;
 pub mod selection;
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AlgorithmCategory {
+    Sorting,
+    DynamicProgramming,
+    StringMatching,
+    Hashing,
+}
+
+impl AlgorithmCategory {
+    pub const ALL: [AlgorithmCategory; 4] = [
+        AlgorithmCategory::Sorting,
+        AlgorithmCategory::DynamicProgramming,
+        AlgorithmCategory::StringMatching,
+        AlgorithmCategory::Hashing,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            AlgorithmCategory::Sorting => "Sorting",
+            AlgorithmCategory::DynamicProgramming => "Dynamic Programming",
+            AlgorithmCategory::StringMatching => "String Matching",
+            AlgorithmCategory::Hashing => "Hashing",
+        }
+    }
+
+    pub fn cycled(self, forward: bool) -> Self {
+        let categories = Self::ALL;
+        let current = categories
+            .iter()
+            .position(|&category| category == self)
+            .unwrap_or(0);
+        let next = if forward {
+            (current + 1) % categories.len()
+        } else {
+            (current + categories.len() - 1) % categories.len()
+        };
+        categories[next]
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct AlgorithmInfo {
+    pub description: &'static str,
+    pub best: &'static str,
+    pub average: &'static str,
+    pub worst: &'static str,
+    pub space: &'static str,
+    pub per: Option<&'static str>,
+    pub stable: Option<bool>,
+    pub in_place: Option<bool>,
+    pub adaptive: Option<bool>,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Element {
+    pub value: i32,
+    pub origin: usize,
+}
+
+impl Element {
+    fn tag_all(values: Vec<i32>) -> Vec<Element> {
+        values
+            .into_iter()
+            .enumerate()
+            .map(|(origin, value)| Element { value, origin })
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StepEvent {
+    Compare(usize, usize),
+    Swap(usize, usize),
+    Write(usize),
+    Read(usize),
+    PivotChosen(usize),
+    RangeEntered { start: usize, end: usize },
+    PassComplete,
+    Done,
+    OutOfOrder(usize, usize),
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct OperationCounts {
+    pub comparisons: usize,
+    pub swaps: usize,
+    pub writes: usize,
+    pub reads: usize,
+}
+
+impl OperationCounts {
+    pub fn record(&mut self, events: &[StepEvent]) {
+        for event in events {
+            match event {
+                StepEvent::Compare(..) => self.comparisons += 1,
+                StepEvent::Swap(..) => self.swaps += 1,
+                StepEvent::Write(_) => self.writes += 1,
+                StepEvent::Read(_) => self.reads += 1,
+                StepEvent::PivotChosen(_)
+                | StepEvent::RangeEntered { .. }
+                | StepEvent::PassComplete
+                | StepEvent::Done
+                | StepEvent::OutOfOrder(..) => {}
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BarState {
+    Idle,
+    Outside,
+    Sorted,
+    Current,
+    Compared,
+    Swapped,
+    Pivot,
+    Fault,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct ArrayView<'a> {
+    pub elements: &'a [Element],
+    pub current: &'a [usize],
+    pub events: &'a [StepEvent],
+    pub pivot: Option<usize>,
+    pub finalized: &'a [bool],
+    pub active: Option<(usize, usize)>,
+    pub sorted_run: Option<usize>,
+}
+
+impl ArrayView<'_> {
+    pub fn label_width(&self) -> usize {
+        let values = self.elements.iter().map(|element| element.value);
+        [values.clone().min(), values.max()]
+            .into_iter()
+            .flatten()
+            .map(|value| value.to_string().len())
+            .max()
+            .unwrap_or(1)
+    }
+
+    pub fn highlighted(&self) -> impl Iterator<Item = usize> + '_ {
+        let events = self.events.iter().flat_map(|event| match *event {
+            StepEvent::Compare(a, b) | StepEvent::Swap(a, b) | StepEvent::OutOfOrder(a, b) => {
+                vec![a, b]
+            }
+            StepEvent::Write(index) => vec![index],
+            _ => Vec::new(),
+        });
+        self.current.iter().copied().chain(self.pivot).chain(events)
+    }
+
+    pub fn is_compared(&self, index: usize) -> bool {
+        self.events.iter().any(|event| match *event {
+            StepEvent::Compare(a, b) => a == index || b == index,
+            _ => false,
+        })
+    }
+
+    pub fn is_swapped(&self, index: usize) -> bool {
+        self.events.iter().any(|event| match *event {
+            StepEvent::Swap(a, b) => a == index || b == index,
+            StepEvent::Write(i) => i == index,
+            _ => false,
+        })
+    }
+
+    pub fn is_fault(&self, index: usize) -> bool {
+        self.events.iter().any(|event| match *event {
+            StepEvent::OutOfOrder(a, b) => a == index || b == index,
+            _ => false,
+        })
+    }
+
+    pub fn is_finalized(&self, index: usize) -> bool {
+        self.finalized.get(index).copied().unwrap_or(false)
+    }
+
+    pub fn is_active(&self, index: usize) -> bool {
+        self.active
+            .is_none_or(|(start, end)| (start..end).contains(&index))
+    }
+
+    pub fn bar_state(&self, index: usize) -> BarState {
+        if self.is_fault(index) {
+            BarState::Fault
+        } else if self.pivot == Some(index) {
+            BarState::Pivot
+        } else if self.is_swapped(index) {
+            BarState::Swapped
+        } else if self.is_compared(index) {
+            BarState::Compared
+        } else if self.current.contains(&index) {
+            BarState::Current
+        } else if self.is_finalized(index) {
+            BarState::Sorted
+        } else if !self.is_active(index) {
+            BarState::Outside
+        } else {
+            BarState::Idle
+        }
+    }
+
+    pub fn sorted_values(&self) -> Vec<i32> {
+        let mut values: Vec<i32> = self.elements.iter().map(|element| element.value).collect();
+        values.sort_unstable();
+        values
+    }
+
+    pub fn equal_key_inversions(&self) -> usize {
+        let mut order: Vec<usize> = (0..self.elements.len()).collect();
+        order.sort_by_key(|&index| self.elements[index].value);
+
+        order
+            .chunk_by(|&a, &b| self.elements[a].value == self.elements[b].value)
+            .map(|group| {
+                let mut origins: Vec<usize> = group
+                    .iter()
+                    .map(|&index| self.elements[index].origin)
+                    .collect();
+                count_inversions(&mut origins)
+            })
+            .sum()
+    }
+
+    pub fn duplicate_ranks(&self) -> Vec<usize> {
+        let mut order: Vec<usize> = (0..self.elements.len()).collect();
+        order.sort_by_key(|&index| (self.elements[index].value, self.elements[index].origin));
+
+        let mut ranks = vec![0; self.elements.len()];
+        for pair in order.windows(2) {
+            if self.elements[pair[0]].value == self.elements[pair[1]].value {
+                ranks[pair[1]] = ranks[pair[0]] + 1;
+            }
+        }
+
+        ranks
+    }
+}
+
+fn count_inversions(values: &mut [usize]) -> usize {
+    if values.len() < 2 {
+        return 0;
+    }
+
+    let mid = values.len() / 2;
+    let mut count = count_inversions(&mut values[..mid]) + count_inversions(&mut values[mid..]);
+    let mut merged = Vec::with_capacity(values.len());
+    let (mut left, mut right) = (0, mid);
+    while left < mid && right < values.len() {
+        if values[left] <= values[right] {
+            merged.push(values[left]);
+            left += 1;
+        } else {
+            merged.push(values[right]);
+            count += mid - left;
+            right += 1;
+        }
+    }
+    merged.extend_from_slice(&values[left..mid]);
+    merged.extend_from_slice(&values[right..]);
+    values.copy_from_slice(&merged);
+
+    count
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum Visualization<'a> {
+    Array(ArrayView<'a>),
+    Table(&'a DpTable),
+    Text(&'a TextMatch),
+    HashTable(&'a HashTableView),
+}
+
 trait Algorithm: Debug {
     type State: Clone;
 
     fn name(&self) -> &'static str;
+    fn short_name(&self) -> &'static str;
+    fn category(&self) -> AlgorithmCategory;
+    fn info(&self) -> AlgorithmInfo;
     fn initial_state(&self, bars: Vec<i32>) -> Self::State;
     fn step(&self, state: &mut Self::State) -> bool;
-    fn get_data(&self, state: &Self::State) -> Vec<i32>;
-    fn get_comparisons(&self, state: &Self::State) -> Vec<(usize, usize)>;
-    fn get_current_indices(&self, state: &Self::State) -> Vec<usize>;
+    fn visualization<'a>(&self, state: &'a Self::State) -> Visualization<'a>;
+    fn narration<'a>(&self, state: &'a Self::State) -> &'a str;
+
+    fn events<'a>(&self, _state: &'a Self::State) -> &'a [StepEvent] {
+        &[]
+    }
+
+    fn pseudocode(&self) -> &'static [&'static str] {
+        &[]
+    }
+
+    fn code_line(&self, _state: &Self::State) -> Option<usize> {
+        None
+    }
+
+    fn input(&self) -> Option<String> {
+        None
+    }
+
+    fn input_format(&self) -> &'static str {
+        ""
+    }
+
+    fn set_input(&mut self, _input: &str) -> Result<(), String> {
+        Err(format!("{} sorts the bars and takes no input", self.name()))
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct TimelineProbe {
+    state: Option<Box<dyn Any>>,
+    steps: usize,
+}
+
+impl TimelineProbe {
+    fn run<A: Algorithm>(
+        &mut self,
+        algo: &A,
+        state: &A::State,
+        limit: usize,
+        deadline: Instant,
+    ) -> ControlFlow<Option<usize>>
+    where
+        A::State: 'static,
+    {
+        let copy = self.state.get_or_insert_with(|| Box::new(state.clone()));
+        let Some(copy) = copy.downcast_mut::<A::State>() else {
+            return ControlFlow::Break(None);
+        };
+
+        while Instant::now() < deadline {
+            if self.steps >= limit {
+                return ControlFlow::Break(None);
+            }
+            self.steps += 1;
+            if algo.step(copy) {
+                return ControlFlow::Break(Some(self.steps));
+            }
+        }
+
+        ControlFlow::Continue(())
+    }
 }
 
 #[derive(Debug)]
@@ -29,72 +397,171 @@ pub enum AlgorithmType {
     QuickSort(QuickSort, Option<QuickSortState>),
     MergeSort(MergeSort, Option<MergeSortState>),
     SelectionSort(SelectionSort, Option<SelectionSortState>),
+    LongestCommonSubsequence(LongestCommonSubsequence, Option<LcsState>),
+    EditDistance(EditDistance, Option<EditDistanceState>),
+    Knapsack(Knapsack, Option<KnapsackState>),
+    CoinChange(CoinChange, Option<CoinChangeState>),
+    LongestIncreasingSubsequence(LongestIncreasingSubsequence, Option<LisState>),
+    NaiveSearch(NaiveSearch, Option<NaiveSearchState>),
+    Kmp(Kmp, Option<KmpState>),
+    BoyerMoore(BoyerMoore, Option<BoyerMooreState>),
+    RabinKarp(RabinKarp, Option<RabinKarpState>),
+    ZAlgorithm(ZAlgorithm, Option<ZAlgorithmState>),
+    LinearProbing(LinearProbing, Option<OpenAddressingState>),
+    QuadraticProbing(QuadraticProbing, Option<OpenAddressingState>),
+    DoubleHashing(DoubleHashing, Option<OpenAddressingState>),
+    SeparateChaining(SeparateChaining, Option<ChainingState>),
+    CuckooHashing(CuckooHashing, Option<CuckooState>),
+}
+
+macro_rules! dispatch {
+    ($value:expr, $algo:ident, $state:ident => $body:expr) => {
+        match $value {
+            AlgorithmType::BubbleSort($algo, $state) => $body,
+            AlgorithmType::QuickSort($algo, $state) => $body,
+            AlgorithmType::MergeSort($algo, $state) => $body,
+            AlgorithmType::SelectionSort($algo, $state) => $body,
+            AlgorithmType::LongestCommonSubsequence($algo, $state) => $body,
+            AlgorithmType::EditDistance($algo, $state) => $body,
+            AlgorithmType::Knapsack($algo, $state) => $body,
+            AlgorithmType::CoinChange($algo, $state) => $body,
+            AlgorithmType::LongestIncreasingSubsequence($algo, $state) => $body,
+            AlgorithmType::NaiveSearch($algo, $state) => $body,
+            AlgorithmType::Kmp($algo, $state) => $body,
+            AlgorithmType::BoyerMoore($algo, $state) => $body,
+            AlgorithmType::RabinKarp($algo, $state) => $body,
+            AlgorithmType::ZAlgorithm($algo, $state) => $body,
+            AlgorithmType::LinearProbing($algo, $state) => $body,
+            AlgorithmType::QuadraticProbing($algo, $state) => $body,
+            AlgorithmType::DoubleHashing($algo, $state) => $body,
+            AlgorithmType::SeparateChaining($algo, $state) => $body,
+            AlgorithmType::CuckooHashing($algo, $state) => $body,
+        }
+    };
 }
 
 impl AlgorithmType {
     pub fn name(&self) -> &'static str {
-        match self {
-            AlgorithmType::BubbleSort(algo, _) => algo.name(),
-            AlgorithmType::QuickSort(algo, _) => algo.name(),
-            AlgorithmType::MergeSort(algo, _) => algo.name(),
-            AlgorithmType::SelectionSort(algo, _) => algo.name(),
-        }
+        dispatch!(self, algo, _state => algo.name())
+    }
+
+    pub fn short_name(&self) -> &'static str {
+        dispatch!(self, algo, _state => algo.short_name())
+    }
+
+    pub fn category(&self) -> AlgorithmCategory {
+        dispatch!(self, algo, _state => algo.category())
+    }
+
+    pub fn info(&self) -> AlgorithmInfo {
+        dispatch!(self, algo, _state => algo.info())
     }
 
     pub fn reset_with_data(&mut self, bars: Vec<i32>) {
-        match self {
-            AlgorithmType::BubbleSort(algo, state) => {
-                *state = Some(algo.initial_state(bars));
-            }
-            AlgorithmType::QuickSort(algo, state) => {
-                *state = Some(algo.initial_state(bars));
-            }
-            AlgorithmType::MergeSort(algo, state) => {
-                *state = Some(algo.initial_state(bars));
-            }
-            AlgorithmType::SelectionSort(algo, state) => {
-                *state = Some(algo.initial_state(bars));
-            }
-        }
+        dispatch!(self, algo, state => *state = Some(algo.initial_state(bars)))
     }
 
     pub fn step(&mut self) -> bool {
-        match self {
-            AlgorithmType::BubbleSort(algo, Some(state)) => algo.step(state),
-            AlgorithmType::QuickSort(algo, Some(state)) => algo.step(state),
-            AlgorithmType::MergeSort(algo, Some(state)) => algo.step(state),
-            AlgorithmType::SelectionSort(algo, Some(state)) => algo.step(state),
-            _ => true,
-        }
+        dispatch!(self, algo, state => match state {
+            Some(state) => algo.step(state),
+            None => true,
+        })
     }
 
-    pub fn get_data(&self) -> Vec<i32> {
-        match self {
-            AlgorithmType::BubbleSort(algo, Some(state)) => algo.get_data(state),
-            AlgorithmType::QuickSort(algo, Some(state)) => algo.get_data(state),
-            AlgorithmType::MergeSort(algo, Some(state)) => algo.get_data(state),
-            AlgorithmType::SelectionSort(algo, Some(state)) => algo.get_data(state),
-            _ => Vec::new(),
-        }
+    /// Replays the run from its current state on a copy kept in `probe`
+    /// until `deadline`, so the length of the whole run can be counted a
+    /// slice at a time. Breaks with the step count once the copy finishes,
+    /// or with `None` once it has taken `limit` steps without finishing.
+    pub fn count_steps(
+        &self,
+        probe: &mut TimelineProbe,
+        limit: usize,
+        deadline: Instant,
+    ) -> ControlFlow<Option<usize>> {
+        dispatch!(self, algo, state => match state {
+            Some(state) => probe.run(algo, state, limit, deadline),
+            None => ControlFlow::Break(None),
+        })
     }
 
-    pub fn get_current_indices(&self) -> Vec<usize> {
-        match self {
-            AlgorithmType::BubbleSort(algo, Some(state)) => algo.get_current_indices(state),
-            AlgorithmType::QuickSort(algo, Some(state)) => algo.get_current_indices(state),
-            AlgorithmType::MergeSort(algo, Some(state)) => algo.get_current_indices(state),
-            AlgorithmType::SelectionSort(algo, Some(state)) => algo.get_current_indices(state),
-            _ => Vec::new(),
-        }
+    pub fn visualization(&self) -> Option<Visualization<'_>> {
+        dispatch!(self, algo, state => state.as_ref().map(|state| algo.visualization(state)))
     }
 
-    pub fn get_comparisons(&self) -> Vec<(usize, usize)> {
-        match self {
-            AlgorithmType::BubbleSort(algo, Some(state)) => algo.get_comparisons(state),
-            AlgorithmType::QuickSort(algo, Some(state)) => algo.get_comparisons(state),
-            AlgorithmType::MergeSort(algo, Some(state)) => algo.get_comparisons(state),
-            AlgorithmType::SelectionSort(algo, Some(state)) => algo.get_comparisons(state),
-            _ => Vec::new(),
-        }
+    pub fn narration(&self) -> Option<&str> {
+        dispatch!(self, algo, state => state.as_ref().map(|state| algo.narration(state)))
+    }
+
+    pub fn events(&self) -> &[StepEvent] {
+        dispatch!(self, algo, state => state.as_ref().map_or(&[], |state| algo.events(state)))
+    }
+
+    pub fn pseudocode(&self) -> &'static [&'static str] {
+        dispatch!(self, algo, _state => algo.pseudocode())
+    }
+
+    pub fn code_line(&self) -> Option<usize> {
+        dispatch!(self, algo, state => state.as_ref().and_then(|state| algo.code_line(state)))
+    }
+
+    pub fn input(&self) -> Option<String> {
+        dispatch!(self, algo, _state => algo.input())
+    }
+
+    pub fn input_format(&self) -> &'static str {
+        dispatch!(self, algo, _state => algo.input_format())
+    }
+
+    pub fn set_input(&mut self, input: &str) -> Result<(), String> {
+        dispatch!(self, algo, _state => algo.set_input(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn inversions(pairs: &[(i32, usize)]) -> usize {
+        let elements: Vec<Element> = pairs
+            .iter()
+            .map(|&(value, origin)| Element { value, origin })
+            .collect();
+        let view = ArrayView {
+            elements: &elements,
+            current: &[],
+            events: &[],
+            pivot: None,
+            finalized: &[],
+            active: None,
+            sorted_run: None,
+        };
+
+        view.equal_key_inversions()
+    }
+
+    #[test]
+    fn equal_key_inversions_ignores_distinct_keys() {
+        assert_eq!(inversions(&[]), 0);
+        assert_eq!(inversions(&[(3, 0), (2, 1), (1, 2)]), 0);
+        assert_eq!(inversions(&[(1, 2), (1, 3), (2, 0), (2, 1)]), 0);
+    }
+
+    #[test]
+    fn equal_key_inversions_counts_reordered_equal_keys() {
+        assert_eq!(inversions(&[(1, 1), (1, 0)]), 1);
+        assert_eq!(inversions(&[(1, 3), (2, 4), (1, 2), (1, 0), (2, 1)]), 4);
+    }
+
+    #[test]
+    fn equal_key_inversions_matches_pairwise_count() {
+        let pairs: Vec<(i32, usize)> = (0..200)
+            .map(|i| (((i * 7) % 5) as i32, (i * 37) % 200))
+            .collect();
+        let expected = (0..pairs.len())
+            .flat_map(|i| (i + 1..pairs.len()).map(move |j| (i, j)))
+            .filter(|&(i, j)| pairs[i].0 == pairs[j].0 && pairs[i].1 > pairs[j].1)
+            .count();
+
+        assert_eq!(inversions(&pairs), expected);
     }
 }
@@ -1,4 +1,17 @@
-use crate::algorithms::Algorithm;
+use crate::algorithms::{
+    Algorithm, AlgorithmCategory, AlgorithmInfo, ArrayView, Element, StepEvent, Visualization,
+};
+
+const PSEUDOCODE: &[&str] = &[
+    "quicksort(lo, hi):",
+    "  if lo >= hi: return",
+    "  pivot ← a[hi]; i ← lo",
+    "  for j in lo .. hi:",
+    "    if a[j] < pivot:",
+    "      swap a[i], a[j]; i ← i + 1",
+    "  swap a[i], a[hi]",
+    "  quicksort(lo, i - 1); quicksort(i + 1, hi)",
+];
 
 #[derive(Debug, Clone)]
 pub struct QuickSortCall {
@@ -13,12 +26,16 @@ pub struct QuickSortCall {
 
 #[derive(Debug, Clone)]
 pub struct QuickSortState {
-    bars: Vec<i32>,
+    bars: Vec<Element>,
     call_stack: Vec<QuickSortCall>,
     complete: bool,
     current_indices: Vec<usize>,
-    comparisons: Vec<(usize, usize)>,
+    events: Vec<StepEvent>,
     pivot_index: Option<usize>,
+    finalized: Vec<bool>,
+    active: Option<(usize, usize)>,
+    line: Option<usize>,
+    note: String,
 }
 
 #[derive(Debug)]
@@ -31,14 +48,40 @@ impl Algorithm for QuickSort {
         "Quick Sort"
     }
 
+    fn short_name(&self) -> &'static str {
+        "Quick"
+    }
+
+    fn category(&self) -> AlgorithmCategory {
+        AlgorithmCategory::Sorting
+    }
+
+    fn info(&self) -> AlgorithmInfo {
+        AlgorithmInfo {
+            description: "Picks a pivot, partitions the range into smaller and larger values around it (Lomuto scheme), then sorts both sides recursively.",
+            best: "O(n log n)",
+            average: "O(n log n)",
+            worst: "O(n²)",
+            space: "O(log n)",
+            per: None,
+            stable: Some(false),
+            in_place: Some(true),
+            adaptive: Some(false),
+        }
+    }
+
     fn initial_state(&self, bars: Vec<i32>) -> Self::State {
         let mut state = QuickSortState {
-            bars,
+            finalized: vec![false; bars.len()],
+            active: None,
+            bars: Element::tag_all(bars),
             call_stack: Vec::new(),
             complete: false,
             current_indices: Vec::new(),
-            comparisons: Vec::new(),
+            events: Vec::new(),
             pivot_index: None,
+            line: None,
+            note: String::new(),
         };
 
         if state.bars.len() > 1 {
@@ -53,6 +96,7 @@ impl Algorithm for QuickSort {
             });
         } else {
             state.complete = true;
+            state.finalized.fill(true);
         }
 
         state
@@ -62,18 +106,25 @@ impl Algorithm for QuickSort {
         if state.complete || state.call_stack.is_empty() {
             state.complete = true;
             state.current_indices.clear();
-            state.comparisons.clear();
+            state.events.clear();
+            state.events.push(StepEvent::Done);
+            state.finalized.fill(true);
+            state.active = None;
             state.pivot_index = None;
+            state.line = None;
 
             return true;
         }
 
         state.current_indices.clear();
-        state.comparisons.clear();
+        state.events.clear();
 
         let mut current_call = state.call_stack.pop().unwrap();
 
         if current_call.low >= current_call.high {
+            if current_call.low == current_call.high {
+                state.finalized[current_call.low] = true;
+            }
             return self.step(state);
         }
 
@@ -84,7 +135,21 @@ impl Algorithm for QuickSort {
             current_call.partition_j = current_call.low;
 
             state.pivot_index = current_call.pivot_index;
+            state.active = Some((current_call.low, current_call.high + 1));
             state.current_indices.push(current_call.high);
+            state.events.push(StepEvent::RangeEntered {
+                start: current_call.low,
+                end: current_call.high + 1,
+            });
+            state.events.push(StepEvent::PivotChosen(current_call.high));
+            state.line = Some(2);
+            state.note = format!(
+                "partition [{}..={}] around pivot bars[{}]={}",
+                current_call.low,
+                current_call.high,
+                current_call.high,
+                state.bars[current_call.high].value
+            );
 
             state.call_stack.push(current_call);
 
@@ -93,29 +158,53 @@ impl Algorithm for QuickSort {
 
         if !current_call.pivot_placed {
             let pivot_idx = current_call.pivot_index.unwrap();
-            let pivot_value = state.bars[pivot_idx];
+            let pivot_value = state.bars[pivot_idx].value;
 
             if current_call.partition_j <= current_call.high {
                 if current_call.partition_j < current_call.high {
                     state
-                        .comparisons
-                        .push((current_call.partition_j, pivot_idx));
+                        .events
+                        .push(StepEvent::Compare(current_call.partition_j, pivot_idx));
                     state.current_indices.push(current_call.partition_j);
                     state.current_indices.push(pivot_idx);
                     state.current_indices.push(current_call.partition_i);
 
-                    if state.bars[current_call.partition_j] < pivot_value {
-                        state
-                            .bars
-                            .swap(current_call.partition_i, current_call.partition_j);
+                    let (i, j) = (current_call.partition_i, current_call.partition_j);
+                    let value = state.bars[j].value;
+                    if value < pivot_value {
+                        state.bars.swap(i, j);
+                        if i != j {
+                            state.events.push(StepEvent::Swap(i, j));
+                        }
 
                         current_call.partition_i += 1;
+                        state.line = Some(5);
+                        state.note = format!(
+                            "compare bars[{j}]={value} with pivot bars[{pivot_idx}]={pivot_value} → swap with i={i}"
+                        );
+                    } else {
+                        state.line = Some(4);
+                        state.note = format!(
+                            "compare bars[{j}]={value} with pivot bars[{pivot_idx}]={pivot_value} → keep"
+                        );
                     }
 
                     current_call.partition_j += 1;
                 } else {
                     state.bars.swap(current_call.partition_i, pivot_idx);
+                    if current_call.partition_i != pivot_idx {
+                        state
+                            .events
+                            .push(StepEvent::Swap(current_call.partition_i, pivot_idx));
+                    }
+                    state.events.push(StepEvent::PassComplete);
+                    state.finalized[current_call.partition_i] = true;
                     current_call.pivot_placed = true;
+                    state.line = Some(6);
+                    state.note = format!(
+                        "place pivot {pivot_value} at index {}",
+                        current_call.partition_i
+                    );
 
                     if current_call.partition_i > current_call.low {
                         state.call_stack.push(QuickSortCall {
@@ -156,23 +245,31 @@ impl Algorithm for QuickSort {
         false
     }
 
-    fn get_data(&self, state: &Self::State) -> Vec<i32> {
-        state.bars.clone()
+    fn visualization<'a>(&self, state: &'a Self::State) -> Visualization<'a> {
+        Visualization::Array(ArrayView {
+            elements: &state.bars,
+            current: &state.current_indices,
+            events: &state.events,
+            pivot: state.pivot_index,
+            finalized: &state.finalized,
+            active: state.active,
+            sorted_run: None,
+        })
     }
 
-    fn get_comparisons(&self, state: &Self::State) -> Vec<(usize, usize)> {
-        state.comparisons.clone()
+    fn narration<'a>(&self, state: &'a Self::State) -> &'a str {
+        &state.note
     }
 
-    fn get_current_indices(&self, state: &Self::State) -> Vec<usize> {
-        let mut indices = state.current_indices.clone();
+    fn events<'a>(&self, state: &'a Self::State) -> &'a [StepEvent] {
+        &state.events
+    }
 
-        if let Some(pivot) = state.pivot_index {
-            if !indices.contains(&pivot) {
-                indices.push(pivot);
-            }
-        }
+    fn pseudocode(&self) -> &'static [&'static str] {
+        PSEUDOCODE
+    }
 
-        indices
+    fn code_line(&self, state: &Self::State) -> Option<usize> {
+        state.line
     }
 }
@@ -1,14 +1,27 @@
-use super::Algorithm;
+use super::{
+    Algorithm, AlgorithmCategory, AlgorithmInfo, ArrayView, Element, StepEvent, Visualization,
+};
+
+const PSEUDOCODE: &[&str] = &[
+    "for i in 0 .. n - 1:",
+    "  min ← i",
+    "  for j in i + 1 .. n:",
+    "    if a[j] < a[min]: min ← j",
+    "  swap a[i], a[min]",
+];
 
 #[derive(Debug, Clone)]
 pub struct SelectionSortState {
-    bars: Vec<i32>,
+    bars: Vec<Element>,
     i: usize,
     j: usize,
     min_idx: usize,
     complete: bool,
-    comparisons: Vec<(usize, usize)>,
+    events: Vec<StepEvent>,
+    finalized: Vec<bool>,
     current_indices: Vec<usize>,
+    line: Option<usize>,
+    note: String,
 }
 
 #[derive(Debug)]
@@ -21,15 +34,40 @@ impl Algorithm for SelectionSort {
         "Selection Sort"
     }
 
+    fn short_name(&self) -> &'static str {
+        "Selection"
+    }
+
+    fn category(&self) -> AlgorithmCategory {
+        AlgorithmCategory::Sorting
+    }
+
+    fn info(&self) -> AlgorithmInfo {
+        AlgorithmInfo {
+            description: "Scans the unsorted suffix for its minimum and swaps it into place, growing the sorted prefix by one element per pass.",
+            best: "O(n²)",
+            average: "O(n²)",
+            worst: "O(n²)",
+            space: "O(1)",
+            per: None,
+            stable: Some(false),
+            in_place: Some(true),
+            adaptive: Some(false),
+        }
+    }
+
     fn initial_state(&self, bars: Vec<i32>) -> Self::State {
         SelectionSortState {
-            bars,
+            finalized: vec![false; bars.len()],
+            bars: Element::tag_all(bars),
             i: 0,
             j: 0,
             min_idx: 0,
             complete: false,
-            comparisons: Vec::new(),
+            events: Vec::new(),
             current_indices: Vec::new(),
+            line: None,
+            note: String::new(),
         }
     }
 
@@ -38,63 +76,111 @@ impl Algorithm for SelectionSort {
             return true;
         }
 
+        state.events.clear();
         let n = state.bars.len();
         if n <= 1 {
             state.complete = true;
+            state.finalized.fill(true);
+            state.events.push(StepEvent::Done);
             return true;
         }
 
-        state.comparisons.clear();
         state.current_indices.clear();
 
         if state.i >= n - 1 {
             state.complete = true;
+            state.finalized.fill(true);
+            state.events.push(StepEvent::Done);
             return true;
         }
 
         if state.j == 0 {
             state.j = state.i + 1;
             state.min_idx = state.i;
+            state.events.push(StepEvent::RangeEntered {
+                start: state.i,
+                end: n,
+            });
         }
 
         if state.j < n {
             state.current_indices = vec![state.i, state.j, state.min_idx];
-            state.comparisons.push((state.j, state.min_idx));
-
-            if state.bars[state.j] < state.bars[state.min_idx] {
-                state.min_idx = state.j;
+            state
+                .events
+                .push(StepEvent::Compare(state.j, state.min_idx));
+
+            let (j, min) = (state.j, state.min_idx);
+            let (value, min_value) = (state.bars[j].value, state.bars[min].value);
+            if value < min_value {
+                state.min_idx = j;
+                state.line = Some(3);
+                state.note = format!(
+                    "compare bars[{j}]={value} with min bars[{min}]={min_value} → new min at {j}"
+                );
+            } else {
+                state.line = Some(2);
+                state.note =
+                    format!("compare bars[{j}]={value} with min bars[{min}]={min_value} → keep");
             }
 
             state.j += 1;
         } else {
-            if state.min_idx != state.i {
-                state.current_indices = vec![state.i, state.min_idx];
-                state.comparisons.push((state.i, state.min_idx));
-                state.bars.swap(state.i, state.min_idx);
+            let (i, min) = (state.i, state.min_idx);
+            if min != i {
+                state.current_indices = vec![i, min];
+                state.events.push(StepEvent::Swap(i, min));
+                state.note = format!(
+                    "swap bars[{i}]={} with min bars[{min}]={}",
+                    state.bars[i].value, state.bars[min].value
+                );
+                state.bars.swap(i, min);
+            } else {
+                state.note = format!("bars[{i}]={} is already the minimum", state.bars[i].value);
             }
 
+            state.finalized[i] = true;
             state.i += 1;
             state.j = 0;
+            state.line = Some(4);
+            state.events.push(StepEvent::PassComplete);
 
             if state.i >= n - 1 {
                 state.complete = true;
                 state.current_indices.clear();
-                state.comparisons.clear();
+                state.line = None;
+                state.finalized.fill(true);
+                state.events.push(StepEvent::Done);
             }
         }
 
         state.complete
     }
 
-    fn get_data(&self, state: &Self::State) -> Vec<i32> {
-        state.bars.clone()
+    fn visualization<'a>(&self, state: &'a Self::State) -> Visualization<'a> {
+        Visualization::Array(ArrayView {
+            elements: &state.bars,
+            current: &state.current_indices,
+            events: &state.events,
+            pivot: None,
+            finalized: &state.finalized,
+            active: (!state.complete).then_some((state.i, state.bars.len())),
+            sorted_run: None,
+        })
+    }
+
+    fn narration<'a>(&self, state: &'a Self::State) -> &'a str {
+        &state.note
+    }
+
+    fn events<'a>(&self, state: &'a Self::State) -> &'a [StepEvent] {
+        &state.events
     }
 
-    fn get_comparisons(&self, state: &Self::State) -> Vec<(usize, usize)> {
-        state.comparisons.clone()
+    fn pseudocode(&self) -> &'static [&'static str] {
+        PSEUDOCODE
     }
 
-    fn get_current_indices(&self, state: &Self::State) -> Vec<usize> {
-        state.current_indices.clone()
+    fn code_line(&self, state: &Self::State) -> Option<usize> {
+        state.line
     }
 }
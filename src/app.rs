@@ -1,15 +1,60 @@
-use crate::algorithms::AlgorithmType;
 use crate::algorithms::bubble::BubbleSort;
+use crate::algorithms::dp::coin_change::CoinChange;
+use crate::algorithms::dp::edit_distance::EditDistance;
+use crate::algorithms::dp::knapsack::Knapsack;
+use crate::algorithms::dp::lcs::LongestCommonSubsequence;
+use crate::algorithms::dp::lis::LongestIncreasingSubsequence;
+use crate::algorithms::hashing::chaining::SeparateChaining;
+use crate::algorithms::hashing::cuckoo::CuckooHashing;
+use crate::algorithms::hashing::double::DoubleHashing;
+use crate::algorithms::hashing::linear::LinearProbing;
+use crate::algorithms::hashing::quadratic::QuadraticProbing;
+use crate::algorithms::input::parse_bars;
+use crate::algorithms::matching::boyer_moore::BoyerMoore;
+use crate::algorithms::matching::kmp::Kmp;
+use crate::algorithms::matching::naive::NaiveSearch;
+use crate::algorithms::matching::rabin_karp::RabinKarp;
+use crate::algorithms::matching::z_algorithm::ZAlgorithm;
 use crate::algorithms::merge::MergeSort;
 use crate::algorithms::quick::QuickSort;
 use crate::algorithms::selection::SelectionSort;
+use crate::algorithms::{
+    AlgorithmCategory, AlgorithmType, ArrayView, OperationCounts, TimelineProbe, Visualization,
+};
+use crate::config::Config;
+use crate::dataset;
 use crate::event::{Event, EventHandler};
-use crossterm::event::{KeyCode, KeyModifiers};
+use crate::keymap::{Action, Keymap};
+use crate::narration::NarrationLog;
+use crate::palette::{Command, CommandPalette, PaletteEntry};
+use crate::theme::{THEMES, Theme};
+use crate::ui::Hit;
+use crate::verify::Verification;
+use color_eyre::eyre::eyre;
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
 use ratatui::{DefaultTerminal, Frame, layout::Rect};
+use serde::{Deserialize, Serialize};
+use std::ops::ControlFlow;
+use std::path::PathBuf;
 use std::time::{Duration, Instant};
 
 pub const MIN_BARS_SIZE: usize = 10;
+pub const MIN_VIEWPORT: (u16, u16) = (24, 10);
+const BARS_INPUT_FORMAT: &str = "comma-separated integers, e.g. 5, 3, 3, 0, -2";
+const DATASET_FORMAT: &str = ".csv, .json array, or one value per line";
+const DEFAULT_DATASET_FILE: &str = "bars.csv";
 const MAX_STEPS_PER_TICK: u32 = 500;
+const STEP_BUDGET: Duration = Duration::from_millis(12);
+const MAX_BARS: usize = 1_000_000;
+const MAX_NORMAL_BARS: usize = 1_000;
+const LARGE_SIZES: &[usize] = &[MAX_NORMAL_BARS, 10_000, 100_000, MAX_BARS];
+const DEFAULT_LARGE_SIZE: usize = 10_000;
+const LOG_SCROLL_LINES: usize = 10;
+const MOUSE_SCROLL_LINES: usize = 3;
+const HELP_PAGE_LINES: u16 = 10;
+const PICKER_PAGE_ROWS: isize = 5;
+const MAX_TIMELINE_STEPS: usize = 200_000;
+const TIMELINE_BUDGET: Duration = Duration::from_millis(4);
 const SPEED_STEPS_MS: &[u64] = &[0, 1, 2, 5, 10, 20, 40, 60, 80, 100, 150, 200, 300, 500, 750, 1000];
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -19,6 +64,99 @@ pub enum AppStatus {
     Completed,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Distribution {
+    Permutation,
+    FewUnique,
+}
+
+impl Distribution {
+    pub fn name(self) -> &'static str {
+        match self {
+            Distribution::Permutation => "Permutation",
+            Distribution::FewUnique => "Few unique",
+        }
+    }
+
+    fn generate(self, len: usize) -> Vec<i32> {
+        match self {
+            Distribution::Permutation => (1..=len as i32).collect(),
+            Distribution::FewUnique => {
+                let keys = (len / 8).clamp(3, 8);
+                (0..len).map(|i| (i % keys) as i32 + 1).collect()
+            }
+        }
+    }
+
+    fn toggled(self) -> Self {
+        match self {
+            Distribution::Permutation => Distribution::FewUnique,
+            Distribution::FewUnique => Distribution::Permutation,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ChartStyle {
+    #[default]
+    Bars,
+    HalfBars,
+    Scatter,
+    Dots,
+    Horizontal,
+    Gradient,
+    Disparity,
+}
+
+impl ChartStyle {
+    pub const ALL: [ChartStyle; 7] = [
+        ChartStyle::Bars,
+        ChartStyle::HalfBars,
+        ChartStyle::Scatter,
+        ChartStyle::Dots,
+        ChartStyle::Horizontal,
+        ChartStyle::Gradient,
+        ChartStyle::Disparity,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            ChartStyle::Bars => "Bars",
+            ChartStyle::HalfBars => "Half-width bars",
+            ChartStyle::Scatter => "Scatter plot",
+            ChartStyle::Dots => "Dot plot",
+            ChartStyle::Horizontal => "Horizontal bars",
+            ChartStyle::Gradient => "Colour strip",
+            ChartStyle::Disparity => "Disparity circle",
+        }
+    }
+
+    fn next(self) -> Self {
+        let index = Self::ALL
+            .iter()
+            .position(|&style| style == self)
+            .unwrap_or(0);
+        Self::ALL[(index + 1) % Self::ALL.len()]
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum PromptKind {
+    #[default]
+    Input,
+    OpenDataset,
+    SaveDataset,
+}
+
+#[derive(Debug, Default)]
+pub struct InputPrompt {
+    pub kind: PromptKind,
+    pub value: String,
+    pub error: Option<String>,
+}
+
 #[derive(Debug)]
 pub struct App {
     pub running: bool,
@@ -29,8 +167,36 @@ pub struct App {
     pub speed: Duration,
     pub last_step: Instant,
     pub bars: Vec<i32>,
+    pub custom_bars: bool,
+    pub large_arrays: bool,
+    pub stashed_bars: Option<(Vec<i32>, bool)>,
+    pub stashed_speed: Option<Duration>,
+    pub inversions: Option<usize>,
+    pub verification: Option<Verification>,
+    pub sorted_values: Vec<i32>,
+    pub dataset_path: Option<PathBuf>,
+    pub distribution: Distribution,
     pub viewport_width: u16,
     pub viewport_height: u16,
+    pub input_prompt: Option<InputPrompt>,
+    pub palette: Option<CommandPalette>,
+    pub help: Option<u16>,
+    pub picker: Option<usize>,
+    pub steps: usize,
+    pub total_steps: Option<usize>,
+    pub timeline_probe: Option<TimelineProbe>,
+    pub counts: OperationCounts,
+    pub log: NarrationLog,
+    pub show_log: bool,
+    pub show_card: bool,
+    pub show_values: bool,
+    pub chart: ChartStyle,
+    pub theme: &'static Theme,
+    pub save_on_exit: bool,
+    pub keymap: Keymap,
+    pub mouse: bool,
+    pub selected_bar: Option<usize>,
+    pub scrubbing: bool,
 }
 
 impl Default for App {
@@ -43,32 +209,171 @@ impl Default for App {
                 AlgorithmType::QuickSort(QuickSort, None),
                 AlgorithmType::MergeSort(MergeSort, None),
                 AlgorithmType::SelectionSort(SelectionSort, None),
+                AlgorithmType::LongestCommonSubsequence(
+                    LongestCommonSubsequence::default(),
+                    None,
+                ),
+                AlgorithmType::EditDistance(EditDistance::default(), None),
+                AlgorithmType::Knapsack(Knapsack::default(), None),
+                AlgorithmType::CoinChange(CoinChange::default(), None),
+                AlgorithmType::LongestIncreasingSubsequence(
+                    LongestIncreasingSubsequence::default(),
+                    None,
+                ),
+                AlgorithmType::NaiveSearch(NaiveSearch::default(), None),
+                AlgorithmType::Kmp(Kmp::default(), None),
+                AlgorithmType::BoyerMoore(BoyerMoore::default(), None),
+                AlgorithmType::RabinKarp(RabinKarp::default(), None),
+                AlgorithmType::ZAlgorithm(ZAlgorithm::default(), None),
+                AlgorithmType::LinearProbing(LinearProbing, None),
+                AlgorithmType::QuadraticProbing(QuadraticProbing, None),
+                AlgorithmType::DoubleHashing(DoubleHashing, None),
+                AlgorithmType::SeparateChaining(SeparateChaining, None),
+                AlgorithmType::CuckooHashing(CuckooHashing, None),
             ],
             current_algorithm: 0,
             app_status: AppStatus::Paused,
             speed: Duration::from_millis(100),
             last_step: Instant::now(),
             bars: (1..=50).collect(),
+            custom_bars: false,
+            large_arrays: false,
+            stashed_bars: None,
+            stashed_speed: None,
+            inversions: None,
+            verification: None,
+            sorted_values: Vec::new(),
+            dataset_path: None,
+            distribution: Distribution::Permutation,
             viewport_width: 80,
             viewport_height: 24,
+            input_prompt: None,
+            palette: None,
+            help: None,
+            picker: None,
+            steps: 0,
+            total_steps: None,
+            timeline_probe: None,
+            counts: OperationCounts::default(),
+            log: NarrationLog::default(),
+            show_log: false,
+            show_card: true,
+            show_values: false,
+            chart: ChartStyle::Bars,
+            theme: &THEMES[0],
+            save_on_exit: false,
+            keymap: Keymap::default(),
+            mouse: true,
+            selected_bar: None,
+            scrubbing: false,
         }
     }
 }
 
 impl App {
-    pub fn new() -> Self {
+    pub fn new(dataset_path: Option<PathBuf>) -> color_eyre::Result<Self> {
         let mut app = Self::default();
-        app.shuffle_data();
+        app.apply_config(&Config::load()?)?;
+        match dataset_path {
+            Some(path) => {
+                app.set_custom_bars(dataset::load(&path, MAX_BARS)?);
+                app.dataset_path = Some(path);
+            }
+            None => app.shuffle_data(),
+        }
         app.reset_algorithm();
-        app
+        Ok(app)
+    }
+
+    fn apply_config(&mut self, config: &Config) -> color_eyre::Result<()> {
+        self.current_algorithm = self
+            .algorithms
+            .iter()
+            .position(|algo| {
+                algo.short_name().eq_ignore_ascii_case(&config.algorithm)
+                    || algo.name().eq_ignore_ascii_case(&config.algorithm)
+            })
+            .ok_or_else(|| eyre!("unknown algorithm {:?} in config", config.algorithm))?;
+        self.theme = Theme::by_name(&config.theme)
+            .ok_or_else(|| eyre!("unknown theme {:?} in config", config.theme))?;
+        self.distribution = config.distribution;
+        self.chart = config.chart;
+        if config.bars > MAX_NORMAL_BARS {
+            return Err(eyre!(
+                "bars = {} in config is above the limit of {MAX_NORMAL_BARS}",
+                config.bars
+            ));
+        }
+        self.generate_bars(config.bars.max(MIN_BARS_SIZE));
+        let speed_ms = SPEED_STEPS_MS
+            .iter()
+            .copied()
+            .min_by_key(|&ms| ms.abs_diff(config.speed_ms))
+            .unwrap_or(100);
+        self.speed = Duration::from_millis(speed_ms);
+        self.save_on_exit = config.save_on_exit;
+        self.keymap =
+            Keymap::new(&config.keys).map_err(|error| eyre!("{error} in config [keys]"))?;
+        self.mouse = config.mouse;
+
+        Ok(())
+    }
+
+    pub fn config(&self) -> Config {
+        Config {
+            algorithm: self.get_current_algorithm().short_name().to_string(),
+            speed_ms: self.stashed_speed.unwrap_or(self.speed).as_millis() as u64,
+            bars: self
+                .stashed_bars
+                .as_ref()
+                .map_or(self.bars.len(), |(bars, _)| bars.len())
+                .min(MAX_NORMAL_BARS),
+            theme: self.theme.name.to_string(),
+            distribution: self.distribution,
+            chart: self.chart,
+            save_on_exit: self.save_on_exit,
+            mouse: self.mouse,
+            keys: self.keymap.overrides().clone(),
+        }
     }
 
     pub fn chart_inner_width(&self) -> u16 {
-        self.viewport_width.saturating_sub(2)
+        self.viewport_width
+            .saturating_sub(self.log_width())
+            .saturating_sub(2)
+    }
+
+    pub fn log_width(&self) -> u16 {
+        if self.show_log && !self.is_tiny_layout() {
+            self.viewport_width * 30 / 100
+        } else {
+            0
+        }
+    }
+
+    pub fn columns_per_cell(&self) -> usize {
+        if self.chart == ChartStyle::HalfBars {
+            2
+        } else {
+            1
+        }
+    }
+
+    pub fn chart_columns(&self) -> usize {
+        self.chart_inner_width() as usize * self.columns_per_cell()
+    }
+
+    /// Most values typed or loaded in the current mode.
+    fn bars_limit(&self) -> usize {
+        if self.large_arrays {
+            MAX_BARS
+        } else {
+            MAX_NORMAL_BARS
+        }
     }
 
     pub fn max_bars(&self) -> usize {
-        let inner = self.chart_inner_width() as usize;
+        let inner = self.chart_columns();
         if inner == 0 {
             return MIN_BARS_SIZE;
         }
@@ -81,13 +386,28 @@ impl App {
         }
     }
 
-    pub fn bar_chart_layout(&self, bar_count: usize) -> (u16, u16) {
-        let inner = self.chart_inner_width() as usize;
+    pub fn has_duplicates(&self) -> bool {
+        self.sorted_values.windows(2).any(|pair| pair[0] == pair[1])
+    }
+
+    pub fn is_downsampled(&self, bar_count: usize) -> bool {
+        bar_count > self.chart_columns()
+    }
+
+    pub fn bar_chart_layout(&self, view: &ArrayView) -> (u16, u16) {
+        let bar_count = view.elements.len();
+        let inner = self.chart_columns();
+        let scale = self.columns_per_cell();
         if bar_count == 0 || inner == 0 {
             return (1, 1);
         }
 
-        if bar_count * 2 <= inner {
+        let label_width = view.label_width();
+        if self.show_values && bar_count * (label_width + 1) * scale <= inner {
+            ((label_width * scale) as u16, scale as u16)
+        } else if bar_count * 2 * scale <= inner {
+            (scale as u16, scale as u16)
+        } else if bar_count * 2 <= inner {
             (1, 1)
         } else {
             (1, 0)
@@ -98,40 +418,21 @@ impl App {
         self.viewport_width < 100 || self.viewport_height < 28
     }
 
+    pub fn is_too_small(&self) -> bool {
+        self.viewport_width < MIN_VIEWPORT.0 || self.viewport_height < MIN_VIEWPORT.1
+    }
+
     pub fn is_tiny_layout(&self) -> bool {
         self.viewport_width < 60 || self.viewport_height < 20
     }
 
     pub fn speed_label(&self) -> String {
-        if self.speed.as_millis() == 0 {
-            "max".to_string()
-        } else {
-            format!("{}ms", self.speed.as_millis())
-        }
+        speed_name(self.speed.as_millis() as u64)
     }
 
     pub fn update_viewport(&mut self, area: Rect) {
         self.viewport_width = area.width;
         self.viewport_height = area.height;
-        self.clamp_bars_to_viewport();
-    }
-
-    fn clamp_bars_to_viewport(&mut self) {
-        let max = self.max_bars();
-        let mut changed = false;
-
-        while self.bars.len() > max {
-            if let Some((index, _)) = self.bars.iter().enumerate().max_by_key(|&(_, &val)| val) {
-                self.bars.remove(index);
-                changed = true;
-            } else {
-                break;
-            }
-        }
-
-        if changed {
-            self.reset_algorithm();
-        }
     }
 
     fn shuffle_data(&mut self) {
@@ -149,7 +450,25 @@ impl App {
     }
 
     fn reset_algorithm(&mut self) {
+        self.rewind();
+        self.total_steps = None;
+        self.timeline_probe = (!self.large_arrays).then(TimelineProbe::default);
+        self.count_timeline();
+        self.selected_bar = self.selected_bar.filter(|&index| index < self.bars.len());
+    }
+
+    fn rewind(&mut self) {
         self.algorithms[self.current_algorithm].reset_with_data(self.bars.clone());
+        self.steps = 0;
+        self.counts = OperationCounts::default();
+        self.log.clear();
+        self.record_narration();
+        self.sorted_values = match self.get_current_algorithm().visualization() {
+            Some(Visualization::Array(view)) => view.sorted_values(),
+            _ => Vec::new(),
+        };
+        self.inversions = None;
+        self.verification = None;
         self.app_status = AppStatus::Paused;
         self.last_step = Instant::now();
         self.event_handler.discard_pending_ticks();
@@ -164,6 +483,10 @@ impl App {
             self.handle_event()?;
         }
 
+        if self.save_on_exit {
+            self.config().save()?;
+        }
+
         Ok(())
     }
 
@@ -181,29 +504,423 @@ impl App {
     }
 
     pub fn handle_crossterm_event(&mut self, event: crossterm::event::Event) {
-        if let crossterm::event::Event::Key(key_event) = event {
-            match key_event.code {
-                KeyCode::Char('q') | KeyCode::Esc => self.quit(),
-                KeyCode::Char('c' | 'C') if key_event.modifiers.contains(KeyModifiers::CONTROL) => {
-                    self.quit();
+        match event {
+            crossterm::event::Event::Key(key_event) => self.handle_key_event(key_event),
+            crossterm::event::Event::Mouse(mouse_event) => self.handle_mouse_event(mouse_event),
+            _ => {}
+        }
+    }
+
+    fn handle_key_event(&mut self, key_event: KeyEvent) {
+        if self.input_prompt.is_some() {
+            self.handle_input_prompt_key(key_event);
+            return;
+        }
+        if self.palette.is_some() {
+            self.handle_palette_key(key_event);
+            return;
+        }
+        if self.help.is_some() {
+            self.handle_help_key(key_event);
+            return;
+        }
+        if self.picker.is_some() {
+            self.handle_picker_key(key_event);
+            return;
+        }
+
+        match self.keymap.action(&key_event) {
+            Some(Action::SelectAlgorithm) => {
+                if let Some(position) = self.keymap.slot(Action::SelectAlgorithm, &key_event) {
+                    self.select_in_category(position);
                 }
-                KeyCode::Char(' ') => self.toggle_running(),
-                KeyCode::Char('r') => self.reset(),
-                KeyCode::Char('s') => self.shuffle_data_and_reset(),
-                KeyCode::Right => self.increase_length(),
-                KeyCode::Left => self.decrease_length(),
-                KeyCode::Up => self.increase_speed(),
-                KeyCode::Down => self.decrease_speed(),
-                KeyCode::Char('1') => self.select_algorithm(0),
-                KeyCode::Char('2') => self.select_algorithm(1),
-                KeyCode::Char('3') => self.select_algorithm(2),
-                KeyCode::Char('4') => self.select_algorithm(3),
+            }
+            Some(action) => self.perform(action),
+            None => {}
+        }
+    }
+
+    fn handle_mouse_event(&mut self, mouse_event: MouseEvent) {
+        if self.input_prompt.is_some() || self.palette.is_some() {
+            return;
+        }
+        if self.picker.is_some() {
+            match mouse_event.kind {
+                MouseEventKind::ScrollUp => self.move_picker(-1),
+                MouseEventKind::ScrollDown => self.move_picker(1),
+                MouseEventKind::Down(MouseButton::Left) => {
+                    match self.picker_hit(mouse_event.column, mouse_event.row) {
+                        Some(Hit::Algorithm(index)) => self.choose_from_picker(index),
+                        Some(_) => {}
+                        None => self.picker = None,
+                    }
+                }
+                _ => {}
+            }
+            return;
+        }
+        let max_offset = self.help_max_offset();
+        if let Some(offset) = self.help.as_mut() {
+            match mouse_event.kind {
+                MouseEventKind::ScrollUp => *offset = offset.saturating_sub(1),
+                MouseEventKind::ScrollDown => *offset = (*offset + 1).min(max_offset),
+                MouseEventKind::Down(_) => self.help = None,
                 _ => {}
             }
+            return;
+        }
+
+        let hit = self.hit_test(mouse_event.column, mouse_event.row);
+        match mouse_event.kind {
+            MouseEventKind::Down(MouseButton::Left) => match hit {
+                Some(Hit::Category) => self.cycle_category(true),
+                Some(Hit::Algorithm(index)) => self.select_algorithm(index),
+                Some(Hit::Bar(index)) => {
+                    self.selected_bar = (self.selected_bar != Some(index)).then_some(index);
+                }
+                Some(Hit::Timeline(step)) => {
+                    self.scrubbing = true;
+                    self.seek(step);
+                }
+                Some(Hit::Log | Hit::Popup) | None => {}
+            },
+            MouseEventKind::Drag(MouseButton::Left) if self.scrubbing => {
+                if let Some(step) = self.timeline_step(mouse_event.column) {
+                    self.seek(step);
+                }
+            }
+            MouseEventKind::Up(MouseButton::Left) => self.scrubbing = false,
+            MouseEventKind::ScrollUp if hit == Some(Hit::Log) => {
+                self.log.scroll_up(MOUSE_SCROLL_LINES);
+            }
+            MouseEventKind::ScrollDown if hit == Some(Hit::Log) => {
+                self.log.scroll_down(MOUSE_SCROLL_LINES);
+            }
+            MouseEventKind::ScrollUp => self.increase_speed(),
+            MouseEventKind::ScrollDown => self.decrease_speed(),
+            _ => {}
+        }
+    }
+
+    fn perform(&mut self, action: Action) {
+        if !self.is_available(action) {
+            return;
+        }
+
+        match action {
+            Action::TogglePlay => self.toggle_running(),
+            Action::Reset => self.reset(),
+            Action::Shuffle => self.shuffle_data_and_reset(),
+            Action::Faster => self.increase_speed(),
+            Action::Slower => self.decrease_speed(),
+            Action::FewerBars => self.decrease_length(),
+            Action::MoreBars => self.increase_length(),
+            Action::ToggleDuplicates => self.toggle_distribution(),
+            Action::EditInput => self.open_input_prompt(),
+            Action::OpenDataset => self.open_dataset_prompt(PromptKind::OpenDataset),
+            Action::SaveDataset => self.open_dataset_prompt(PromptKind::SaveDataset),
+            Action::NextCategory => self.cycle_category(true),
+            Action::PreviousCategory => self.cycle_category(false),
+            Action::ToggleLog => self.show_log = !self.show_log,
+            Action::ToggleCard => self.show_card = !self.show_card,
+            Action::ToggleLargeArrays => self.toggle_large_arrays(),
+            Action::ToggleValues => self.show_values = !self.show_values,
+            Action::ExportLog => self.export_log(),
+            Action::ScrollLogUp => self.log.scroll_up(LOG_SCROLL_LINES),
+            Action::ScrollLogDown => self.log.scroll_down(LOG_SCROLL_LINES),
+            Action::FollowLog => self.log.follow(),
+            Action::CycleTheme => self.theme = self.theme.next(),
+            Action::CycleChart => self.chart = self.chart.next(),
+            Action::ShowHelp => self.help = Some(0),
+            Action::OpenPalette => self.palette = Some(CommandPalette::default()),
+            Action::OpenPicker => self.picker = Some(self.current_algorithm),
+            Action::Quit => self.quit(),
+            Action::SelectAlgorithm
+            | Action::MoveUp
+            | Action::MoveDown
+            | Action::PageUp
+            | Action::PageDown
+            | Action::MoveFirst
+            | Action::MoveLast
+            | Action::Confirm
+            | Action::Cancel => {}
+        }
+    }
+
+    pub fn is_available(&self, action: Action) -> bool {
+        let takes_input = self.get_current_algorithm().input().is_some();
+        match action {
+            Action::FewerBars | Action::MoreBars if self.large_arrays && self.custom_bars => false,
+            Action::FewerBars
+            | Action::MoreBars
+            | Action::ToggleDuplicates
+            | Action::ToggleValues
+            | Action::CycleChart
+            | Action::OpenDataset
+            | Action::SaveDataset => !takes_input,
+            Action::ToggleLargeArrays => {
+                self.large_arrays || self.current_category() == AlgorithmCategory::Sorting
+            }
+            Action::NextCategory | Action::PreviousCategory => !self.large_arrays,
+            Action::ScrollLogUp | Action::ScrollLogDown | Action::FollowLog => self.show_log,
+            _ => true,
+        }
+    }
+
+    fn handle_help_key(&mut self, key_event: KeyEvent) {
+        let max_offset = self.help_max_offset();
+        if self.keymap.interrupts(&key_event) {
+            self.quit();
+            return;
+        }
+        let Some(offset) = self.help.as_mut() else {
+            return;
+        };
+
+        match self.keymap.menu_action(&key_event) {
+            Some(Action::MoveUp) => *offset = offset.saturating_sub(1),
+            Some(Action::MoveDown) => *offset = (*offset + 1).min(max_offset),
+            Some(Action::PageUp) => *offset = offset.saturating_sub(HELP_PAGE_LINES),
+            Some(Action::PageDown) => *offset = (*offset + HELP_PAGE_LINES).min(max_offset),
+            Some(Action::MoveFirst) => *offset = 0,
+            Some(Action::MoveLast) => *offset = max_offset,
+            _ => self.help = None,
+        }
+    }
+
+    fn handle_picker_key(&mut self, key_event: KeyEvent) {
+        let Some(highlighted) = self.picker else {
+            return;
+        };
+
+        if self.keymap.interrupts(&key_event) {
+            self.quit();
+            return;
+        }
+
+        match self.keymap.menu_action(&key_event) {
+            Some(Action::Cancel) => self.picker = None,
+            Some(Action::Confirm) => self.choose_from_picker(highlighted),
+            Some(Action::MoveUp) => self.move_picker(-1),
+            Some(Action::MoveDown) => self.move_picker(1),
+            Some(Action::PageUp) => self.move_picker(-PICKER_PAGE_ROWS),
+            Some(Action::PageDown) => self.move_picker(PICKER_PAGE_ROWS),
+            Some(Action::MoveFirst) => self.move_picker(isize::MIN),
+            Some(Action::MoveLast) => self.move_picker(isize::MAX),
+            _ => {
+                let forward = match self.keymap.action(&key_event) {
+                    Some(Action::NextCategory) => true,
+                    Some(Action::PreviousCategory) => false,
+                    _ => return,
+                };
+                let category = self.algorithms[highlighted].category().cycled(forward);
+                if let Some(&index) = self.algorithms_in_category(category).first() {
+                    self.picker = Some(index);
+                }
+            }
+        }
+    }
+
+    pub fn picker_order(&self) -> Vec<usize> {
+        AlgorithmCategory::ALL
+            .into_iter()
+            .flat_map(|category| self.algorithms_in_category(category))
+            .collect()
+    }
+
+    fn move_picker(&mut self, delta: isize) {
+        let Some(highlighted) = self.picker else {
+            return;
+        };
+
+        let order = self.picker_order();
+        let position = order
+            .iter()
+            .position(|&index| index == highlighted)
+            .unwrap_or(0);
+        let next = position.saturating_add_signed(delta).min(order.len() - 1);
+        self.picker = Some(order[next]);
+    }
+
+    fn choose_from_picker(&mut self, index: usize) {
+        self.picker = None;
+        if index != self.current_algorithm {
+            self.select_algorithm(index);
+        }
+    }
+
+    fn handle_palette_key(&mut self, key_event: KeyEvent) {
+        let entries = self.palette_entries();
+        let Some(palette) = self.palette.as_mut() else {
+            return;
+        };
+        let count = palette.matches(&entries).len();
+
+        if self.keymap.interrupts(&key_event) {
+            self.quit();
+            return;
+        }
+        match key_event.code {
+            KeyCode::Char(c) if !key_event.modifiers.contains(KeyModifiers::CONTROL) => {
+                palette.push(c);
+                return;
+            }
+            KeyCode::Backspace => {
+                palette.pop();
+                return;
+            }
+            _ => {}
+        }
+
+        match self.keymap.menu_action(&key_event) {
+            Some(Action::Cancel) => self.palette = None,
+            Some(Action::Confirm) => {
+                let command = palette
+                    .matches(&entries)
+                    .get(palette.selected)
+                    .map(|entry| entry.command);
+                self.palette = None;
+                if let Some(command) = command {
+                    self.run_command(command);
+                }
+            }
+            Some(Action::MoveUp) => palette.select_previous(count),
+            Some(Action::MoveDown) => palette.select_next(count),
+            _ => match self.keymap.action(&key_event) {
+                Some(Action::PreviousCategory) => palette.select_previous(count),
+                Some(Action::NextCategory) => palette.select_next(count),
+                _ => {}
+            },
+        }
+    }
+
+    pub fn palette_entries(&self) -> Vec<PaletteEntry> {
+        let current = |selected: bool| if selected { "current" } else { "" }.to_string();
+        let mut entries: Vec<PaletteEntry> = self
+            .algorithms
+            .iter()
+            .enumerate()
+            .filter(|&(index, _)| self.is_selectable(index))
+            .map(|(index, algo)| PaletteEntry {
+                kind: "algorithm",
+                label: algo.name().to_string(),
+                hint: algo.category().name().to_string(),
+                command: Command::Algorithm(index),
+            })
+            .collect();
+
+        for action in Action::ALL {
+            if action != Action::OpenPalette
+                && action != Action::SelectAlgorithm
+                && !action.is_menu()
+                && self.is_available(action)
+            {
+                entries.push(PaletteEntry {
+                    kind: "action",
+                    label: action.description().to_string(),
+                    hint: self.keymap.keys(action).join("/"),
+                    command: Command::Action(action),
+                });
+            }
+        }
+
+        if self.is_available(Action::ToggleDuplicates) {
+            for distribution in [Distribution::Permutation, Distribution::FewUnique] {
+                entries.push(PaletteEntry {
+                    kind: "keys",
+                    label: distribution.name().to_string(),
+                    hint: current(distribution == self.distribution),
+                    command: Command::Distribution(distribution),
+                });
+            }
+        }
+
+        if self.is_available(Action::CycleChart) {
+            for style in ChartStyle::ALL {
+                entries.push(PaletteEntry {
+                    kind: "chart",
+                    label: style.name().to_string(),
+                    hint: current(style == self.chart),
+                    command: Command::Chart(style),
+                });
+            }
+        }
+
+        for theme in &THEMES {
+            entries.push(PaletteEntry {
+                kind: "theme",
+                label: theme.name.to_string(),
+                hint: current(theme.name == self.theme.name),
+                command: Command::Theme(theme),
+            });
+        }
+
+        for &ms in SPEED_STEPS_MS {
+            entries.push(PaletteEntry {
+                kind: "speed",
+                label: speed_name(ms),
+                hint: current(ms == self.speed.as_millis() as u64),
+                command: Command::Speed(ms),
+            });
+        }
+
+        entries.push(PaletteEntry {
+            kind: "setting",
+            label: "Save settings on exit".to_string(),
+            hint: if self.save_on_exit { "on" } else { "off" }.to_string(),
+            command: Command::SaveOnExit(!self.save_on_exit),
+        });
+
+        entries
+    }
+
+    fn run_command(&mut self, command: Command) {
+        match command {
+            Command::Action(action) => self.perform(action),
+            Command::Algorithm(index) => self.select_algorithm(index),
+            Command::Distribution(distribution) => self.set_distribution(distribution),
+            Command::Chart(style) => self.chart = style,
+            Command::Theme(theme) => self.theme = theme,
+            Command::Speed(ms) => self.speed = Duration::from_millis(ms),
+            Command::SaveOnExit(enabled) => self.save_on_exit = enabled,
+        }
+    }
+
+    fn handle_input_prompt_key(&mut self, key_event: KeyEvent) {
+        let Some(prompt) = self.input_prompt.as_mut() else {
+            return;
+        };
+
+        if self.keymap.interrupts(&key_event) {
+            self.quit();
+            return;
+        }
+        match key_event.code {
+            KeyCode::Char(c) if !key_event.modifiers.contains(KeyModifiers::CONTROL) => {
+                prompt.value.push(c);
+                prompt.error = None;
+                return;
+            }
+            KeyCode::Backspace => {
+                prompt.value.pop();
+                prompt.error = None;
+                return;
+            }
+            _ => {}
+        }
+
+        match self.keymap.menu_action(&key_event) {
+            Some(Action::Cancel) => self.input_prompt = None,
+            Some(Action::Confirm) => self.submit_input_prompt(),
+            _ => {}
         }
     }
 
     pub fn tick(&mut self) {
+        self.count_timeline();
+        if self.app_status == AppStatus::Completed {
+            self.sweep_verification();
+        }
         if self.app_status != AppStatus::Running {
             return;
         }
@@ -213,16 +930,24 @@ impl App {
             return;
         }
 
-        let steps = if speed_ms == 0 {
-            MAX_STEPS_PER_TICK
+        let max_steps = if self.large_arrays {
+            u32::MAX
         } else {
-            (self.last_step.elapsed().as_millis() / speed_ms).max(1) as u32
-        }
-        .min(MAX_STEPS_PER_TICK);
+            MAX_STEPS_PER_TICK
+        };
+        let steps = self
+            .last_step
+            .elapsed()
+            .as_millis()
+            .checked_div(speed_ms)
+            .map_or(max_steps, |steps| {
+                steps.max(1).min(u128::from(max_steps)) as u32
+            })
+            .min(max_steps);
 
+        let started = Instant::now();
         for _ in 0..steps {
-            if self.algorithms[self.current_algorithm].step() {
-                self.app_status = AppStatus::Completed;
+            if self.advance() || started.elapsed() >= STEP_BUDGET {
                 break;
             }
         }
@@ -230,6 +955,90 @@ impl App {
         self.last_step = Instant::now();
     }
 
+    fn advance(&mut self) -> bool {
+        let done = self.algorithms[self.current_algorithm].step();
+        self.steps += 1;
+        self.counts
+            .record(self.algorithms[self.current_algorithm].events());
+        self.record_narration();
+        if done {
+            self.app_status = AppStatus::Completed;
+            self.total_steps = Some(self.steps);
+            self.timeline_probe = None;
+            if self.has_duplicates()
+                && let Some(Visualization::Array(view)) =
+                    self.get_current_algorithm().visualization()
+            {
+                self.inversions = Some(view.equal_key_inversions());
+            }
+            if let Some(Visualization::Array(view)) = self.get_current_algorithm().visualization() {
+                self.verification = Some(Verification::new(view.elements.len()));
+                self.sweep_verification();
+            }
+        }
+        done
+    }
+
+    fn sweep_verification(&mut self) {
+        let (Some(verification), Some(Visualization::Array(view))) = (
+            self.verification.as_mut(),
+            self.algorithms[self.current_algorithm].visualization(),
+        ) else {
+            return;
+        };
+        if !verification.sweep(view.elements) {
+            return;
+        }
+
+        let entry = match verification.first_fault() {
+            None => "verified: every adjacent pair is in order".to_string(),
+            Some(index) => format!(
+                "verification failed: {} adjacent pair(s) out of order, first bars[{index}]={} > bars[{}]={}",
+                verification.faults(),
+                view.elements[index].value,
+                index + 1,
+                view.elements[index + 1].value
+            ),
+        };
+        self.log.push(entry);
+    }
+
+    pub fn visualization(&self) -> Option<Visualization<'_>> {
+        let visualization = self.get_current_algorithm().visualization()?;
+        Some(match (visualization, &self.verification) {
+            (Visualization::Array(view), Some(verification)) => {
+                Visualization::Array(verification.view(view))
+            }
+            (visualization, _) => visualization,
+        })
+    }
+
+    fn count_timeline(&mut self) {
+        let Some(probe) = self.timeline_probe.as_mut() else {
+            return;
+        };
+
+        let deadline = Instant::now() + TIMELINE_BUDGET;
+        if let ControlFlow::Break(total) =
+            self.algorithms[self.current_algorithm].count_steps(probe, MAX_TIMELINE_STEPS, deadline)
+        {
+            self.total_steps = total;
+            self.timeline_probe = None;
+        }
+    }
+
+    fn seek(&mut self, target: usize) {
+        if target < self.steps {
+            self.rewind();
+        }
+        while self.steps < target && self.app_status != AppStatus::Completed {
+            self.advance();
+        }
+        if self.app_status == AppStatus::Running {
+            self.app_status = AppStatus::Paused;
+        }
+    }
+
     pub fn quit(&mut self) {
         self.running = false;
     }
@@ -260,33 +1069,236 @@ impl App {
         self.reset_algorithm();
     }
 
+    fn toggle_distribution(&mut self) {
+        self.set_distribution(self.distribution.toggled());
+    }
+
+    fn set_distribution(&mut self, distribution: Distribution) {
+        self.distribution = distribution;
+        self.generate_bars(self.bars.len());
+        self.shuffle_data_and_reset();
+    }
+
     fn select_algorithm(&mut self, index: usize) {
-        if index < self.algorithms.len() {
+        if index < self.algorithms.len() && self.is_selectable(index) {
             self.current_algorithm = index;
             self.reset_algorithm();
         }
     }
 
+    fn select_in_category(&mut self, position: usize) {
+        let indices = self.algorithms_in_category(self.current_category());
+        if let Some(&index) = indices.get(position) {
+            self.select_algorithm(index);
+        }
+    }
+
+    fn cycle_category(&mut self, forward: bool) {
+        let category = self.current_category().cycled(forward);
+        if let Some(&index) = self.algorithms_in_category(category).first() {
+            self.select_algorithm(index);
+        }
+    }
+
+    fn record_narration(&mut self) {
+        let Some(narration) = self.algorithms[self.current_algorithm].narration() else {
+            return;
+        };
+        if narration.is_empty() {
+            return;
+        }
+
+        let entry = format!("#{} {narration}", self.steps);
+        self.log.push(entry);
+    }
+
+    fn export_log(&mut self) {
+        let path = PathBuf::from(format!(
+            "{}-log.txt",
+            self.get_current_algorithm().short_name().to_lowercase()
+        ));
+
+        self.log.notice = Some(match self.log.export(&path) {
+            Ok(()) => format!("saved {}", path.display()),
+            Err(error) => format!("export failed: {error}"),
+        });
+    }
+
+    pub fn input_format(&self) -> &'static str {
+        let current_algo = self.get_current_algorithm();
+        if self
+            .input_prompt
+            .as_ref()
+            .is_some_and(|prompt| prompt.kind != PromptKind::Input)
+        {
+            DATASET_FORMAT
+        } else if current_algo.input().is_some() {
+            current_algo.input_format()
+        } else {
+            BARS_INPUT_FORMAT
+        }
+    }
+
+    fn open_input_prompt(&mut self) {
+        let value = self.get_current_algorithm().input().unwrap_or_else(|| {
+            self.bars
+                .iter()
+                .map(i32::to_string)
+                .collect::<Vec<_>>()
+                .join(", ")
+        });
+        self.input_prompt = Some(InputPrompt {
+            value,
+            ..InputPrompt::default()
+        });
+    }
+
+    fn open_dataset_prompt(&mut self, kind: PromptKind) {
+        let value = match (&self.dataset_path, kind) {
+            (Some(path), _) => path.display().to_string(),
+            (None, PromptKind::SaveDataset) => DEFAULT_DATASET_FILE.to_string(),
+            (None, _) => String::new(),
+        };
+        self.input_prompt = Some(InputPrompt {
+            kind,
+            value,
+            error: None,
+        });
+    }
+
+    fn submit_input_prompt(&mut self) {
+        let Some(prompt) = self.input_prompt.as_ref() else {
+            return;
+        };
+
+        let value = prompt.value.clone();
+        let result = match prompt.kind {
+            PromptKind::Input => self.apply_input(&value),
+            PromptKind::OpenDataset => self.load_dataset(PathBuf::from(value.trim())),
+            PromptKind::SaveDataset => self.save_dataset(PathBuf::from(value.trim())),
+        };
+        match result {
+            Ok(()) => self.input_prompt = None,
+            Err(error) => {
+                if let Some(prompt) = self.input_prompt.as_mut() {
+                    prompt.error = Some(error);
+                }
+            }
+        }
+    }
+
+    fn apply_input(&mut self, value: &str) -> Result<(), String> {
+        if self.get_current_algorithm().input().is_some() {
+            self.algorithms[self.current_algorithm].set_input(value)?;
+        } else {
+            self.set_custom_bars(parse_bars(value, self.bars_limit())?);
+        }
+        self.reset_algorithm();
+
+        Ok(())
+    }
+
+    fn load_dataset(&mut self, path: PathBuf) -> Result<(), String> {
+        let bars = dataset::load(&path, self.bars_limit()).map_err(|error| format!("{error:#}"))?;
+        self.set_custom_bars(bars);
+        self.dataset_path = Some(path);
+        self.reset_algorithm();
+
+        Ok(())
+    }
+
+    fn save_dataset(&mut self, path: PathBuf) -> Result<(), String> {
+        dataset::save(&path, &self.bars).map_err(|error| format!("{error:#}"))?;
+        self.log.notice = Some(format!("saved {}", path.display()));
+        self.dataset_path = Some(path);
+
+        Ok(())
+    }
+
+    fn set_custom_bars(&mut self, bars: Vec<i32>) {
+        self.bars = bars;
+        self.custom_bars = true;
+    }
+
     fn increase_length(&mut self) {
+        if self.large_arrays {
+            if let Some(&len) = LARGE_SIZES.iter().find(|&&len| len > self.bars.len()) {
+                self.generate_bars(len);
+                self.shuffle_data_and_reset();
+            }
+            return;
+        }
         if self.bars.len() >= self.max_bars() {
             return;
         }
+        if self.custom_bars {
+            let max = self.bars.iter().copied().max().unwrap_or(0);
+            self.bars.push(max.saturating_add(1));
+            self.reset_algorithm();
+            return;
+        }
 
-        self.bars.push((self.bars.len() + 1) as i32);
+        self.generate_bars(self.bars.len() + 1);
         self.shuffle_data_and_reset();
     }
 
     fn decrease_length(&mut self) {
+        if self.large_arrays {
+            if let Some(&len) = LARGE_SIZES.iter().rev().find(|&&len| len < self.bars.len()) {
+                self.generate_bars(len);
+                self.shuffle_data_and_reset();
+            }
+            return;
+        }
         if self.bars.len() <= MIN_BARS_SIZE {
             return;
         }
-
-        if let Some((index, _)) = self.bars.iter().enumerate().max_by_key(|&(_, &val)| val) {
-            self.bars.remove(index);
+        if self.custom_bars {
+            if let Some((index, _)) = self
+                .bars
+                .iter()
+                .enumerate()
+                .max_by_key(|&(_, &value)| value)
+            {
+                self.bars.remove(index);
+            }
+            self.reset_algorithm();
+            return;
         }
+
+        self.generate_bars(self.bars.len() - 1);
         self.shuffle_data_and_reset();
     }
 
+    fn toggle_large_arrays(&mut self) {
+        self.large_arrays = !self.large_arrays;
+        if self.large_arrays {
+            self.stashed_bars = Some((std::mem::take(&mut self.bars), self.custom_bars));
+            self.generate_bars(DEFAULT_LARGE_SIZE);
+            self.stashed_speed = Some(self.speed);
+            self.speed = Duration::ZERO;
+            self.shuffle_data_and_reset();
+            return;
+        }
+
+        match self.stashed_bars.take() {
+            Some((bars, custom)) => {
+                self.bars = bars;
+                self.custom_bars = custom;
+            }
+            None => self.generate_bars(Config::default().bars),
+        }
+        if let Some(speed) = self.stashed_speed.take() {
+            self.speed = speed;
+        }
+        self.reset_algorithm();
+    }
+
+    fn generate_bars(&mut self, len: usize) {
+        self.bars = self.distribution.generate(len);
+        self.custom_bars = false;
+    }
+
     fn speed_index(&self) -> usize {
         let current = self.speed.as_millis() as u64;
         SPEED_STEPS_MS
@@ -316,4 +1328,31 @@ impl App {
     pub fn get_current_algorithm(&self) -> &AlgorithmType {
         &self.algorithms[self.current_algorithm]
     }
+
+    pub fn current_category(&self) -> AlgorithmCategory {
+        self.get_current_algorithm().category()
+    }
+
+    /// Large arrays are only meant for the sorts, so the other categories
+    /// are off limits until the bars are back to their normal size.
+    pub fn is_selectable(&self, index: usize) -> bool {
+        !self.large_arrays || self.algorithms[index].category() == AlgorithmCategory::Sorting
+    }
+
+    pub fn algorithms_in_category(&self, category: AlgorithmCategory) -> Vec<usize> {
+        self.algorithms
+            .iter()
+            .enumerate()
+            .filter(|(_, algo)| algo.category() == category)
+            .map(|(index, _)| index)
+            .collect()
+    }
+}
+
+fn speed_name(ms: u64) -> String {
+    if ms == 0 {
+        "max".to_string()
+    } else {
+        format!("{ms}ms")
+    }
 }
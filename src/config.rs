@@ -0,0 +1,69 @@
+use crate::app::{ChartStyle, Distribution};
+use crate::keymap::Action;
+use color_eyre::eyre::{WrapErr, eyre};
+use serde::{Deserialize, Serialize};
+use std::{collections::BTreeMap, fs, io, path::PathBuf};
+
+const CONFIG_DIR: &str = "tui-algorithm-visualizer";
+const CONFIG_FILE: &str = "config.toml";
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, rename_all = "kebab-case", deny_unknown_fields)]
+pub struct Config {
+    pub algorithm: String,
+    pub speed_ms: u64,
+    pub bars: usize,
+    pub theme: String,
+    pub distribution: Distribution,
+    pub chart: ChartStyle,
+    pub save_on_exit: bool,
+    pub mouse: bool,
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub keys: BTreeMap<Action, Vec<String>>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            algorithm: "Bubble".to_string(),
+            speed_ms: 100,
+            bars: 50,
+            theme: "default".to_string(),
+            distribution: Distribution::Permutation,
+            chart: ChartStyle::Bars,
+            save_on_exit: false,
+            mouse: true,
+            keys: BTreeMap::new(),
+        }
+    }
+}
+
+impl Config {
+    pub fn path() -> Option<PathBuf> {
+        dirs::config_dir().map(|dir| dir.join(CONFIG_DIR).join(CONFIG_FILE))
+    }
+
+    pub fn load() -> color_eyre::Result<Self> {
+        let Some(path) = Self::path() else {
+            return Ok(Self::default());
+        };
+
+        match fs::read_to_string(&path) {
+            Ok(contents) => toml::from_str(&contents)
+                .wrap_err_with(|| format!("invalid config file {}", path.display())),
+            Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(error) => Err(error).wrap_err_with(|| format!("failed to read {}", path.display())),
+        }
+    }
+
+    pub fn save(&self) -> color_eyre::Result<()> {
+        let path = Self::path().ok_or_else(|| eyre!("no config directory on this system"))?;
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)
+                .wrap_err_with(|| format!("failed to create {}", dir.display()))?;
+        }
+
+        let contents = toml::to_string_pretty(self).wrap_err("failed to serialize config")?;
+        fs::write(&path, contents).wrap_err_with(|| format!("failed to write {}", path.display()))
+    }
+}
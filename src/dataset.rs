@@ -0,0 +1,171 @@
+use color_eyre::eyre::{WrapErr, bail, eyre};
+use std::fs;
+use std::path::Path;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DatasetFormat {
+    Csv,
+    Json,
+    Lines,
+}
+
+impl DatasetFormat {
+    pub fn of(path: &Path) -> Self {
+        match path
+            .extension()
+            .and_then(|extension| extension.to_str())
+            .map(str::to_ascii_lowercase)
+            .as_deref()
+        {
+            Some("csv") => DatasetFormat::Csv,
+            Some("json") => DatasetFormat::Json,
+            _ => DatasetFormat::Lines,
+        }
+    }
+
+    fn parse(self, contents: &str) -> Result<Vec<i64>, String> {
+        match self {
+            DatasetFormat::Json => serde_json::from_str(contents)
+                .map_err(|error| format!("expected a JSON array of integers: {error}")),
+            DatasetFormat::Csv | DatasetFormat::Lines => {
+                let mut lines = contents
+                    .lines()
+                    .map(str::trim)
+                    .filter(|line| !line.is_empty() && !line.starts_with('#'))
+                    .peekable();
+                if self == DatasetFormat::Csv
+                    && lines
+                        .peek()
+                        .is_some_and(|header| header.chars().any(char::is_alphabetic))
+                {
+                    lines.next();
+                }
+
+                lines
+                    .flat_map(|line| line.split(',').map(str::trim))
+                    .filter(|field| !field.is_empty())
+                    .map(|field| {
+                        field
+                            .parse::<i64>()
+                            .map_err(|_| format!("'{field}' is not a whole number"))
+                    })
+                    .collect()
+            }
+        }
+    }
+
+    fn format(self, bars: &[i32]) -> String {
+        let values: Vec<String> = bars.iter().map(i32::to_string).collect();
+        match self {
+            DatasetFormat::Csv => format!("{}\n", values.join(",")),
+            DatasetFormat::Json => format!("[{}]\n", values.join(", ")),
+            DatasetFormat::Lines => values.iter().map(|value| format!("{value}\n")).collect(),
+        }
+    }
+}
+
+pub fn load(path: &Path, max_len: usize) -> color_eyre::Result<Vec<i32>> {
+    let contents =
+        fs::read_to_string(path).wrap_err_with(|| format!("failed to read {}", path.display()))?;
+    let values = DatasetFormat::of(path)
+        .parse(&contents)
+        .map_err(|error| eyre!("{error} in {}", path.display()))?;
+    if values.is_empty() {
+        bail!("{} contains no values", path.display());
+    }
+    if values.len() > max_len {
+        bail!(
+            "{} has {} values, more than the {max_len} supported",
+            path.display(),
+            values.len()
+        );
+    }
+
+    values
+        .into_iter()
+        .map(|value| {
+            i32::try_from(value).map_err(|_| eyre!("{value} is out of range in {}", path.display()))
+        })
+        .collect()
+}
+
+pub fn save(path: &Path, bars: &[i32]) -> color_eyre::Result<()> {
+    fs::write(path, DatasetFormat::of(path).format(bars))
+        .wrap_err_with(|| format!("failed to write {}", path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn temp_file(name: &str, contents: &str) -> PathBuf {
+        let path = std::env::temp_dir().join(format!("dataset-{}-{name}", std::process::id()));
+        fs::write(&path, contents).unwrap();
+        path
+    }
+
+    #[test]
+    fn format_follows_the_extension() {
+        assert_eq!(DatasetFormat::of(Path::new("a.CSV")), DatasetFormat::Csv);
+        assert_eq!(DatasetFormat::of(Path::new("a.json")), DatasetFormat::Json);
+        assert_eq!(DatasetFormat::of(Path::new("a.txt")), DatasetFormat::Lines);
+        assert_eq!(DatasetFormat::of(Path::new("bars")), DatasetFormat::Lines);
+    }
+
+    #[test]
+    fn csv_skips_header_comments_and_blank_fields() {
+        let parsed = DatasetFormat::Csv.parse("value\n# week 3\n5, 3,\n\n-2,0\n");
+        assert_eq!(parsed, Ok(vec![5, 3, -2, 0]));
+    }
+
+    #[test]
+    fn lines_have_no_header_row() {
+        assert_eq!(
+            DatasetFormat::Lines.parse("4\n 8 \n# note\n15\n"),
+            Ok(vec![4, 8, 15])
+        );
+        assert!(DatasetFormat::Lines.parse("value\n4\n").is_err());
+        assert!(DatasetFormat::Csv.parse("1,2.5").is_err());
+    }
+
+    #[test]
+    fn json_reads_an_array_of_integers() {
+        assert_eq!(DatasetFormat::Json.parse("[3, -1, 4]"), Ok(vec![3, -1, 4]));
+        assert!(DatasetFormat::Json.parse("{\"bars\": [1]}").is_err());
+        assert!(DatasetFormat::Json.parse("[1, 2.5]").is_err());
+    }
+
+    #[test]
+    fn saved_values_load_back() {
+        let bars = [5, -3, 0, 12];
+        for name in ["round.csv", "round.json", "round.txt"] {
+            let path = temp_file(name, "");
+            save(&path, &bars).unwrap();
+            let loaded = load(&path, bars.len());
+            fs::remove_file(&path).unwrap();
+
+            assert_eq!(loaded.unwrap(), bars, "{name}");
+        }
+    }
+
+    #[test]
+    fn load_enforces_the_length_and_range() {
+        let path = temp_file("limits.csv", "1,2,3\n");
+        let too_long = load(&path, 2);
+        let fits = load(&path, 3);
+        fs::remove_file(&path).unwrap();
+        assert!(too_long.is_err());
+        assert_eq!(fits.unwrap(), [1, 2, 3]);
+
+        let path = temp_file("empty.json", "[]");
+        let empty = load(&path, 10);
+        fs::remove_file(&path).unwrap();
+        assert!(empty.is_err());
+
+        let path = temp_file("range.txt", "1\n3000000000\n");
+        let out_of_range = load(&path, 10);
+        fs::remove_file(&path).unwrap();
+        assert!(out_of_range.is_err());
+    }
+}
@@ -0,0 +1,678 @@
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Action {
+    TogglePlay,
+    Reset,
+    Shuffle,
+    Faster,
+    Slower,
+    FewerBars,
+    MoreBars,
+    ToggleDuplicates,
+    ToggleValues,
+    ToggleLargeArrays,
+    EditInput,
+    OpenDataset,
+    SaveDataset,
+    NextCategory,
+    PreviousCategory,
+    ToggleLog,
+    ExportLog,
+    ScrollLogUp,
+    ScrollLogDown,
+    FollowLog,
+    CycleTheme,
+    CycleChart,
+    ShowHelp,
+    OpenPalette,
+    OpenPicker,
+    ToggleCard,
+    SelectAlgorithm,
+    MoveUp,
+    MoveDown,
+    PageUp,
+    PageDown,
+    MoveFirst,
+    MoveLast,
+    Confirm,
+    Cancel,
+    Quit,
+}
+
+impl Action {
+    pub const ALL: [Action; 36] = [
+        Action::TogglePlay,
+        Action::Reset,
+        Action::Shuffle,
+        Action::Faster,
+        Action::Slower,
+        Action::FewerBars,
+        Action::MoreBars,
+        Action::ToggleDuplicates,
+        Action::ToggleValues,
+        Action::ToggleLargeArrays,
+        Action::EditInput,
+        Action::OpenDataset,
+        Action::SaveDataset,
+        Action::NextCategory,
+        Action::PreviousCategory,
+        Action::ToggleLog,
+        Action::ExportLog,
+        Action::ScrollLogUp,
+        Action::ScrollLogDown,
+        Action::FollowLog,
+        Action::CycleTheme,
+        Action::CycleChart,
+        Action::ShowHelp,
+        Action::OpenPalette,
+        Action::OpenPicker,
+        Action::ToggleCard,
+        Action::SelectAlgorithm,
+        Action::MoveUp,
+        Action::MoveDown,
+        Action::PageUp,
+        Action::PageDown,
+        Action::MoveFirst,
+        Action::MoveLast,
+        Action::Confirm,
+        Action::Cancel,
+        Action::Quit,
+    ];
+
+    pub fn description(self) -> &'static str {
+        match self {
+            Action::TogglePlay => "Start or pause (shuffles again once completed)",
+            Action::Reset => "Reset the run with the same data",
+            Action::Shuffle => "Shuffle the bars and reset",
+            Action::Faster => "Speed up",
+            Action::Slower => "Slow down",
+            Action::FewerBars => "Fewer bars",
+            Action::MoreBars => "More bars",
+            Action::ToggleDuplicates => "Toggle duplicate keys",
+            Action::ToggleValues => "Show or hide value labels on the bars",
+            Action::ToggleLargeArrays => "Toggle large arrays (up to a million bars)",
+            Action::EditInput => "Edit the algorithm input or type your own bars",
+            Action::OpenDataset => "Load the bars from a file",
+            Action::SaveDataset => "Save the bars to a file",
+            Action::NextCategory => "Next category",
+            Action::PreviousCategory => "Previous category",
+            Action::ToggleLog => "Show or hide the step log",
+            Action::ExportLog => "Export the step log to a text file",
+            Action::ScrollLogUp => "Scroll the step log up",
+            Action::ScrollLogDown => "Scroll the step log down",
+            Action::FollowLog => "Jump to the newest log entry",
+            Action::CycleTheme => "Cycle colour theme",
+            Action::CycleChart => "Cycle chart style",
+            Action::ShowHelp => "Show all keys and mouse gestures",
+            Action::OpenPalette => "Open the command palette",
+            Action::OpenPicker => "Choose from the list of all algorithms",
+            Action::ToggleCard => "Expand or collapse the algorithm card",
+            Action::SelectAlgorithm => "Select an algorithm in the current category",
+            Action::MoveUp => "Move up in a list or popup",
+            Action::MoveDown => "Move down in a list or popup",
+            Action::PageUp => "Page up in a list or popup",
+            Action::PageDown => "Page down in a list or popup",
+            Action::MoveFirst => "Jump to the top of a list or popup",
+            Action::MoveLast => "Jump to the bottom of a list or popup",
+            Action::Confirm => "Choose the highlighted entry or apply the input",
+            Action::Cancel => "Close a list or popup, or discard the input",
+            Action::Quit => "Quit",
+        }
+    }
+
+    /// Whether the action only applies inside a list, popup or prompt.
+    ///
+    /// Those actions are looked up separately from the main screen's, so the
+    /// same key can move through a list and, say, speed up the playback.
+    pub fn is_menu(self) -> bool {
+        matches!(
+            self,
+            Action::MoveUp
+                | Action::MoveDown
+                | Action::PageUp
+                | Action::PageDown
+                | Action::MoveFirst
+                | Action::MoveLast
+                | Action::Confirm
+                | Action::Cancel
+        )
+    }
+}
+
+const DEFAULT_BINDINGS: &[(Action, &[&str])] = &[
+    (Action::TogglePlay, &["space"]),
+    (Action::Reset, &["r"]),
+    (Action::Shuffle, &["s"]),
+    (Action::Faster, &["up"]),
+    (Action::Slower, &["down"]),
+    (Action::FewerBars, &["left"]),
+    (Action::MoreBars, &["right"]),
+    (Action::ToggleDuplicates, &["d"]),
+    (Action::ToggleValues, &["v"]),
+    (Action::ToggleLargeArrays, &["x"]),
+    (Action::EditInput, &["i"]),
+    (Action::OpenDataset, &["o"]),
+    (Action::SaveDataset, &["w"]),
+    (Action::NextCategory, &["tab"]),
+    (Action::PreviousCategory, &["shift+tab"]),
+    (Action::ToggleLog, &["l"]),
+    (Action::ExportLog, &["e"]),
+    (Action::ScrollLogUp, &["pgup"]),
+    (Action::ScrollLogDown, &["pgdn"]),
+    (Action::FollowLog, &["end"]),
+    (Action::CycleTheme, &["t"]),
+    (Action::CycleChart, &["g"]),
+    (Action::ShowHelp, &["?"]),
+    (Action::OpenPalette, &[":"]),
+    (Action::OpenPicker, &["a"]),
+    (Action::ToggleCard, &["c"]),
+    (
+        Action::SelectAlgorithm,
+        &["1", "2", "3", "4", "5", "6", "7", "8", "9"],
+    ),
+    (Action::MoveUp, &["up"]),
+    (Action::MoveDown, &["down"]),
+    (Action::PageUp, &["pgup"]),
+    (Action::PageDown, &["pgdn"]),
+    (Action::MoveFirst, &["home"]),
+    (Action::MoveLast, &["end"]),
+    (Action::Confirm, &["enter"]),
+    (Action::Cancel, &["esc"]),
+    (Action::Quit, &["q", "esc", "ctrl+c"]),
+];
+
+const INTERRUPT: &str = "ctrl+c";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HelpLine {
+    Playback,
+    Navigation,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct HelpGroup {
+    pub actions: &'static [Action],
+    pub description: &'static str,
+    pub short: &'static str,
+    pub line: HelpLine,
+}
+
+pub const HELP_GROUPS: &[HelpGroup] = &[
+    HelpGroup {
+        actions: &[Action::ShowHelp],
+        description: "All keys and gestures",
+        short: "Help",
+        line: HelpLine::Navigation,
+    },
+    HelpGroup {
+        actions: &[Action::OpenPalette],
+        description: "Command palette",
+        short: "Commands",
+        line: HelpLine::Navigation,
+    },
+    HelpGroup {
+        actions: &[Action::TogglePlay],
+        description: "Start/Pause",
+        short: "Play",
+        line: HelpLine::Playback,
+    },
+    HelpGroup {
+        actions: &[Action::Reset],
+        description: "Reset",
+        short: "Reset",
+        line: HelpLine::Playback,
+    },
+    HelpGroup {
+        actions: &[Action::Shuffle],
+        description: "Shuffle & Reset",
+        short: "Shuffle",
+        line: HelpLine::Playback,
+    },
+    HelpGroup {
+        actions: &[Action::Faster, Action::Slower],
+        description: "Speed Up/Down",
+        short: "Speed",
+        line: HelpLine::Playback,
+    },
+    HelpGroup {
+        actions: &[Action::FewerBars, Action::MoreBars],
+        description: "Decrease/Increase bars",
+        short: "Bars",
+        line: HelpLine::Playback,
+    },
+    HelpGroup {
+        actions: &[Action::ToggleDuplicates],
+        description: "Toggle duplicate keys",
+        short: "Duplicates",
+        line: HelpLine::Playback,
+    },
+    HelpGroup {
+        actions: &[Action::ToggleValues],
+        description: "Toggle value labels",
+        short: "Values",
+        line: HelpLine::Playback,
+    },
+    HelpGroup {
+        actions: &[Action::ToggleLargeArrays],
+        description: "Toggle large arrays",
+        short: "Large",
+        line: HelpLine::Playback,
+    },
+    HelpGroup {
+        actions: &[Action::EditInput],
+        description: "Edit input",
+        short: "Input",
+        line: HelpLine::Playback,
+    },
+    HelpGroup {
+        actions: &[Action::OpenDataset, Action::SaveDataset],
+        description: "Open/Save dataset",
+        short: "File",
+        line: HelpLine::Playback,
+    },
+    HelpGroup {
+        actions: &[Action::OpenPicker],
+        description: "All algorithms",
+        short: "Algorithms",
+        line: HelpLine::Navigation,
+    },
+    HelpGroup {
+        actions: &[Action::NextCategory, Action::PreviousCategory],
+        description: "Next/Previous category",
+        short: "Category",
+        line: HelpLine::Navigation,
+    },
+    HelpGroup {
+        actions: &[Action::ToggleLog, Action::ExportLog],
+        description: "Toggle/Export step log",
+        short: "Log",
+        line: HelpLine::Navigation,
+    },
+    HelpGroup {
+        actions: &[
+            Action::ScrollLogUp,
+            Action::ScrollLogDown,
+            Action::FollowLog,
+        ],
+        description: "Scroll log",
+        short: "Scroll",
+        line: HelpLine::Navigation,
+    },
+    HelpGroup {
+        actions: &[Action::ToggleCard],
+        description: "Expand/Collapse algorithm card",
+        short: "Card",
+        line: HelpLine::Navigation,
+    },
+    HelpGroup {
+        actions: &[Action::CycleTheme],
+        description: "Cycle theme",
+        short: "Theme",
+        line: HelpLine::Navigation,
+    },
+    HelpGroup {
+        actions: &[Action::CycleChart],
+        description: "Cycle chart style",
+        short: "Chart",
+        line: HelpLine::Navigation,
+    },
+    HelpGroup {
+        actions: &[Action::Quit],
+        description: "Quit",
+        short: "Quit",
+        line: HelpLine::Navigation,
+    },
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KeyBinding {
+    code: KeyCode,
+    modifiers: KeyModifiers,
+}
+
+impl KeyBinding {
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let mut modifiers = KeyModifiers::NONE;
+        let mut rest = text;
+        loop {
+            let lower = rest.to_ascii_lowercase();
+            if lower.starts_with("ctrl+") && rest.len() > 5 {
+                modifiers |= KeyModifiers::CONTROL;
+                rest = &rest[5..];
+            } else if lower.starts_with("alt+") && rest.len() > 4 {
+                modifiers |= KeyModifiers::ALT;
+                rest = &rest[4..];
+            } else {
+                break;
+            }
+        }
+
+        let code = match rest.to_ascii_lowercase().as_str() {
+            "space" => KeyCode::Char(' '),
+            "up" => KeyCode::Up,
+            "down" => KeyCode::Down,
+            "left" => KeyCode::Left,
+            "right" => KeyCode::Right,
+            "enter" => KeyCode::Enter,
+            "esc" | "escape" => KeyCode::Esc,
+            "tab" => KeyCode::Tab,
+            "shift+tab" | "backtab" => KeyCode::BackTab,
+            "backspace" => KeyCode::Backspace,
+            "home" => KeyCode::Home,
+            "end" => KeyCode::End,
+            "pgup" | "pageup" => KeyCode::PageUp,
+            "pgdn" | "pagedown" => KeyCode::PageDown,
+            lower => {
+                let mut chars = rest.chars();
+                match (chars.next(), chars.next()) {
+                    (Some(c), None) => KeyCode::Char(c),
+                    _ => match lower.strip_prefix('f').and_then(|n| n.parse().ok()) {
+                        Some(n @ 1..=12) => KeyCode::F(n),
+                        _ => return Err(format!("unknown key {text:?}")),
+                    },
+                }
+            }
+        };
+
+        Ok(Self { code, modifiers })
+    }
+
+    fn matches(&self, event: &KeyEvent) -> bool {
+        let mut modifiers = event.modifiers;
+        if matches!(event.code, KeyCode::Char(_) | KeyCode::BackTab) {
+            modifiers.remove(KeyModifiers::SHIFT);
+        }
+
+        self.code == event.code && self.modifiers == modifiers
+    }
+
+    pub fn label(&self) -> String {
+        let key = match self.code {
+            KeyCode::Char(' ') => "Space".to_string(),
+            KeyCode::Char(c) if c.is_ascii_uppercase() => format!("Shift+{c}"),
+            KeyCode::Char(c) => c.to_uppercase().to_string(),
+            KeyCode::Up => "↑".to_string(),
+            KeyCode::Down => "↓".to_string(),
+            KeyCode::Left => "←".to_string(),
+            KeyCode::Right => "→".to_string(),
+            KeyCode::BackTab => "Shift+Tab".to_string(),
+            KeyCode::PageUp => "PgUp".to_string(),
+            KeyCode::PageDown => "PgDn".to_string(),
+            KeyCode::F(n) => format!("F{n}"),
+            code => code.to_string(),
+        };
+
+        let mut label = String::new();
+        if self.modifiers.contains(KeyModifiers::CONTROL) {
+            label.push_str("Ctrl+");
+        }
+        if self.modifiers.contains(KeyModifiers::ALT) {
+            label.push_str("Alt+");
+        }
+        label.push_str(&key);
+        label
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Keymap {
+    bindings: Vec<(KeyBinding, Action)>,
+    overrides: BTreeMap<Action, Vec<String>>,
+}
+
+impl Default for Keymap {
+    fn default() -> Self {
+        Self::new(&BTreeMap::new()).expect("default key bindings parse")
+    }
+}
+
+impl Keymap {
+    pub fn new(overrides: &BTreeMap<Action, Vec<String>>) -> Result<Self, String> {
+        let interrupt = KeyBinding::parse(INTERRUPT)?;
+        let mut custom: Vec<(KeyBinding, Action)> = Vec::new();
+        for (&action, keys) in overrides {
+            for key in keys {
+                let binding = KeyBinding::parse(key)?;
+                if binding == interrupt && action != Action::Quit {
+                    return Err(format!("{key:?} is reserved for quit"));
+                }
+                if custom
+                    .iter()
+                    .any(|&(taken, bound)| taken == binding && bound.is_menu() == action.is_menu())
+                {
+                    return Err(format!("{key:?} is bound more than once"));
+                }
+                custom.push((binding, action));
+            }
+        }
+
+        let mut bindings = Vec::new();
+        for &(action, keys) in DEFAULT_BINDINGS {
+            if overrides.contains_key(&action) {
+                continue;
+            }
+            for key in keys {
+                let binding = KeyBinding::parse(key)?;
+                if custom
+                    .iter()
+                    .all(|&(taken, bound)| taken != binding || bound.is_menu() != action.is_menu())
+                {
+                    bindings.push((binding, action));
+                }
+            }
+        }
+        bindings.extend(custom);
+        if bindings.iter().all(|(binding, _)| *binding != interrupt) {
+            bindings.push((interrupt, Action::Quit));
+        }
+
+        Ok(Self {
+            bindings,
+            overrides: overrides.clone(),
+        })
+    }
+
+    pub fn overrides(&self) -> &BTreeMap<Action, Vec<String>> {
+        &self.overrides
+    }
+
+    pub fn action(&self, event: &KeyEvent) -> Option<Action> {
+        self.bindings
+            .iter()
+            .find(|(binding, action)| !action.is_menu() && binding.matches(event))
+            .map(|&(_, action)| action)
+    }
+
+    pub fn menu_action(&self, event: &KeyEvent) -> Option<Action> {
+        self.bindings
+            .iter()
+            .find(|(binding, action)| action.is_menu() && binding.matches(event))
+            .map(|&(_, action)| action)
+    }
+
+    pub fn interrupts(&self, event: &KeyEvent) -> bool {
+        KeyBinding::parse(INTERRUPT).is_ok_and(|binding| binding.matches(event))
+    }
+
+    /// Position of the pressed key among the keys of `action`, so that the
+    /// n-th key of `select-algorithm` picks the n-th algorithm.
+    pub fn slot(&self, action: Action, event: &KeyEvent) -> Option<usize> {
+        self.bindings
+            .iter()
+            .filter(|&&(_, bound)| bound == action)
+            .position(|(binding, _)| binding.matches(event))
+    }
+
+    pub fn keys(&self, action: Action) -> Vec<String> {
+        self.bindings
+            .iter()
+            .filter(|&&(_, bound)| bound == action)
+            .map(|(binding, _)| binding.label())
+            .collect()
+    }
+
+    pub fn range_label(&self, action: Action, count: usize) -> Option<String> {
+        let keys = self.keys(action);
+        let keys = &keys[..count.min(keys.len())];
+        match keys {
+            [] => None,
+            [key] => Some(key.clone()),
+            [first, .., last] => Some(format!("{first}-{last}")),
+        }
+    }
+
+    pub fn group_label(&self, group: &HelpGroup, all_keys: bool) -> String {
+        match group.actions {
+            [action] if all_keys => self.keys(*action).join("/"),
+            actions => self.first_keys(actions),
+        }
+    }
+
+    pub fn first_keys(&self, actions: &[Action]) -> String {
+        let keys: Vec<String> = actions
+            .iter()
+            .filter_map(|&action| self.keys(action).into_iter().next())
+            .collect();
+
+        keys.join("/")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn press(code: KeyCode, modifiers: KeyModifiers) -> KeyEvent {
+        KeyEvent::new(code, modifiers)
+    }
+
+    fn overrides(entries: &[(Action, &[&str])]) -> BTreeMap<Action, Vec<String>> {
+        entries
+            .iter()
+            .map(|&(action, keys)| (action, keys.iter().map(|key| key.to_string()).collect()))
+            .collect()
+    }
+
+    #[test]
+    fn parse_reads_names_modifiers_and_characters() {
+        let parsed = |text| KeyBinding::parse(text).map(|binding| binding.label());
+
+        assert_eq!(parsed("space"), Ok("Space".to_string()));
+        assert_eq!(parsed("PgDn"), Ok("PgDn".to_string()));
+        assert_eq!(parsed("shift+tab"), Ok("Shift+Tab".to_string()));
+        assert_eq!(parsed("ctrl+alt+x"), Ok("Ctrl+Alt+X".to_string()));
+        assert_eq!(parsed("K"), Ok("Shift+K".to_string()));
+        assert_eq!(parsed("f12"), Ok("F12".to_string()));
+        assert_eq!(parsed("+"), Ok("+".to_string()));
+        assert!(parsed("f13").is_err());
+        assert!(parsed("ctrl+").is_err());
+        assert!(parsed("hyper").is_err());
+    }
+
+    #[test]
+    fn default_bindings_resolve_actions() {
+        let keymap = Keymap::default();
+
+        let space = press(KeyCode::Char(' '), KeyModifiers::NONE);
+        assert_eq!(keymap.action(&space), Some(Action::TogglePlay));
+        let question = press(KeyCode::Char('?'), KeyModifiers::SHIFT);
+        assert_eq!(keymap.action(&question), Some(Action::ShowHelp));
+        let interrupt = press(KeyCode::Char('c'), KeyModifiers::CONTROL);
+        assert_eq!(keymap.action(&interrupt), Some(Action::Quit));
+        assert_eq!(
+            keymap.action(&press(KeyCode::F(5), KeyModifiers::NONE)),
+            None
+        );
+    }
+
+    #[test]
+    fn overrides_replace_defaults_and_shadow_other_actions() {
+        let keymap = Keymap::new(&overrides(&[(Action::Reset, &["s"])])).unwrap();
+
+        let s = press(KeyCode::Char('s'), KeyModifiers::NONE);
+        assert_eq!(keymap.action(&s), Some(Action::Reset));
+        assert_eq!(
+            keymap.action(&press(KeyCode::Char('r'), KeyModifiers::NONE)),
+            None
+        );
+        assert_eq!(keymap.keys(Action::Reset), ["S"]);
+        assert!(keymap.keys(Action::Shuffle).is_empty());
+    }
+
+    #[test]
+    fn overriding_quit_keeps_the_interrupt_key() {
+        let keymap = Keymap::new(&overrides(&[(Action::Quit, &["x"])])).unwrap();
+
+        let interrupt = press(KeyCode::Char('c'), KeyModifiers::CONTROL);
+        assert_eq!(keymap.action(&interrupt), Some(Action::Quit));
+        assert_eq!(
+            keymap.action(&press(KeyCode::Esc, KeyModifiers::NONE)),
+            None
+        );
+        assert_eq!(keymap.keys(Action::Quit), ["X", "Ctrl+C"]);
+    }
+
+    #[test]
+    fn menu_keys_are_looked_up_apart_from_the_main_screen() {
+        let keymap = Keymap::new(&overrides(&[
+            (Action::Faster, &["k"]),
+            (Action::MoveUp, &["k", "up"]),
+        ]))
+        .unwrap();
+
+        let k = press(KeyCode::Char('k'), KeyModifiers::NONE);
+        assert_eq!(keymap.action(&k), Some(Action::Faster));
+        assert_eq!(keymap.menu_action(&k), Some(Action::MoveUp));
+        let up = press(KeyCode::Up, KeyModifiers::NONE);
+        assert_eq!(keymap.action(&up), None);
+        assert_eq!(keymap.menu_action(&up), Some(Action::MoveUp));
+        let esc = press(KeyCode::Esc, KeyModifiers::NONE);
+        assert_eq!(keymap.action(&esc), Some(Action::Quit));
+        assert_eq!(keymap.menu_action(&esc), Some(Action::Cancel));
+    }
+
+    #[test]
+    fn algorithm_keys_select_by_position() {
+        let keymap = Keymap::default();
+        let three = press(KeyCode::Char('3'), KeyModifiers::NONE);
+
+        assert_eq!(keymap.action(&three), Some(Action::SelectAlgorithm));
+        assert_eq!(keymap.slot(Action::SelectAlgorithm, &three), Some(2));
+        assert_eq!(
+            keymap.range_label(Action::SelectAlgorithm, 4).as_deref(),
+            Some("1-4")
+        );
+
+        let keymap = Keymap::new(&overrides(&[(Action::SelectAlgorithm, &["f1", "f2"])])).unwrap();
+        let f2 = press(KeyCode::F(2), KeyModifiers::NONE);
+        assert_eq!(keymap.slot(Action::SelectAlgorithm, &f2), Some(1));
+        assert_eq!(keymap.action(&three), None);
+        assert_eq!(
+            keymap.range_label(Action::SelectAlgorithm, 6).as_deref(),
+            Some("F1-F2")
+        );
+        assert_eq!(
+            keymap.range_label(Action::SelectAlgorithm, 1).as_deref(),
+            Some("F1")
+        );
+    }
+
+    #[test]
+    fn overrides_reject_bad_keys_and_duplicates() {
+        assert!(Keymap::new(&overrides(&[(Action::Reset, &["hyper"])])).is_err());
+        assert!(Keymap::new(&overrides(&[(Action::Reset, &["ctrl+c"])])).is_err());
+        assert!(Keymap::new(&overrides(&[(Action::Reset, &["x", "x"])])).is_err());
+        assert!(
+            Keymap::new(&overrides(&[
+                (Action::Reset, &["x"]),
+                (Action::Shuffle, &["x"])
+            ]))
+            .is_err()
+        );
+        assert!(Keymap::new(&overrides(&[(Action::Quit, &["ctrl+c", "q"])])).is_ok());
+    }
+}
@@ -1,17 +1,43 @@
 mod algorithms;
 mod app;
+mod config;
+mod dataset;
 mod event;
+mod keymap;
+mod narration;
+mod palette;
+mod theme;
 mod ui;
+mod verify;
 
 use app::App;
+use crossterm::event::{DisableMouseCapture, EnableMouseCapture};
+use std::io;
+use std::path::PathBuf;
 
 fn main() -> color_eyre::Result<()> {
     color_eyre::install()?;
 
+    let app = App::new(std::env::args_os().nth(1).map(PathBuf::from))?;
+    let mouse = app.mouse;
     let terminal = ratatui::init();
-    let result = App::new().run(terminal);
+    let result = set_mouse_capture(mouse, true).and_then(|()| app.run(terminal));
 
+    let _ = set_mouse_capture(mouse, false);
     ratatui::restore();
 
     result
 }
+
+fn set_mouse_capture(mouse: bool, enabled: bool) -> color_eyre::Result<()> {
+    if !mouse {
+        return Ok(());
+    }
+
+    if enabled {
+        crossterm::execute!(io::stdout(), EnableMouseCapture)?;
+    } else {
+        crossterm::execute!(io::stdout(), DisableMouseCapture)?;
+    }
+    Ok(())
+}
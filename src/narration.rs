@@ -0,0 +1,64 @@
+use std::collections::VecDeque;
+use std::io;
+use std::path::Path;
+
+const MAX_ENTRIES: usize = 10_000;
+
+#[derive(Debug, Default)]
+pub struct NarrationLog {
+    entries: VecDeque<String>,
+    offset: usize,
+    pub notice: Option<String>,
+}
+
+impl NarrationLog {
+    pub fn push(&mut self, entry: String) {
+        if self.entries.len() == MAX_ENTRIES {
+            self.entries.pop_front();
+        } else if self.offset > 0 {
+            self.offset += 1;
+        }
+        self.entries.push_back(entry);
+    }
+
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.offset = 0;
+        self.notice = None;
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_following(&self) -> bool {
+        self.offset == 0
+    }
+
+    pub fn scroll_up(&mut self, lines: usize) {
+        self.offset = (self.offset + lines).min(self.entries.len().saturating_sub(1));
+    }
+
+    pub fn scroll_down(&mut self, lines: usize) {
+        self.offset = self.offset.saturating_sub(lines);
+    }
+
+    pub fn follow(&mut self) {
+        self.offset = 0;
+    }
+
+    pub fn visible(&self, height: usize) -> impl Iterator<Item = &String> {
+        let end = self.entries.len() - self.offset.min(self.entries.len());
+        let start = end.saturating_sub(height);
+        self.entries.range(start..end)
+    }
+
+    pub fn export(&self, path: &Path) -> io::Result<()> {
+        let mut contents = String::new();
+        for entry in &self.entries {
+            contents.push_str(entry);
+            contents.push('\n');
+        }
+        std::fs::write(path, contents)
+    }
+}
@@ -0,0 +1,153 @@
+use crate::app::{ChartStyle, Distribution};
+use crate::keymap::Action;
+use crate::theme::Theme;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Command {
+    Action(Action),
+    Algorithm(usize),
+    Distribution(Distribution),
+    Chart(ChartStyle),
+    Theme(&'static Theme),
+    Speed(u64),
+    SaveOnExit(bool),
+}
+
+#[derive(Debug, Clone)]
+pub struct PaletteEntry {
+    pub kind: &'static str,
+    pub label: String,
+    pub hint: String,
+    pub command: Command,
+}
+
+#[derive(Debug, Default)]
+pub struct CommandPalette {
+    pub query: String,
+    pub selected: usize,
+}
+
+impl CommandPalette {
+    pub fn matches<'a>(&self, entries: &'a [PaletteEntry]) -> Vec<&'a PaletteEntry> {
+        let mut scored: Vec<(u32, &PaletteEntry)> = entries
+            .iter()
+            .filter_map(|entry| {
+                let text = format!("{} {}", entry.kind, entry.label);
+                fuzzy_score(&self.query, &text).map(|score| (score, entry))
+            })
+            .collect();
+        scored.sort_by_key(|&(score, _)| std::cmp::Reverse(score));
+        scored.into_iter().map(|(_, entry)| entry).collect()
+    }
+
+    pub fn push(&mut self, c: char) {
+        self.query.push(c);
+        self.selected = 0;
+    }
+
+    pub fn pop(&mut self) {
+        self.query.pop();
+        self.selected = 0;
+    }
+
+    pub fn select_next(&mut self, count: usize) {
+        if count > 0 {
+            self.selected = (self.selected + 1) % count;
+        }
+    }
+
+    pub fn select_previous(&mut self, count: usize) {
+        if count > 0 {
+            self.selected = (self.selected + count - 1) % count;
+        }
+    }
+}
+
+pub fn fuzzy_score(query: &str, text: &str) -> Option<u32> {
+    let text: Vec<char> = text.to_lowercase().chars().collect();
+    query
+        .to_lowercase()
+        .split_whitespace()
+        .map(|term| term_score(term, &text))
+        .sum()
+}
+
+fn term_score(term: &str, text: &[char]) -> Option<u32> {
+    let mut score = 0;
+    let mut next = 0;
+    let mut previous: Option<usize> = None;
+
+    for wanted in term.chars() {
+        let position = next + text[next..].iter().position(|&c| c == wanted)?;
+        score += 1;
+        if position > 0 && previous == Some(position - 1) {
+            score += 4;
+        }
+        if position == 0 || !text[position - 1].is_alphanumeric() {
+            score += 3;
+        }
+        previous = Some(position);
+        next = position + 1;
+    }
+
+    Some(score)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fuzzy_score_needs_every_term_in_order() {
+        assert!(fuzzy_score("qck", "Quick Sort").is_some());
+        assert!(fuzzy_score("sort quick", "Quick Sort").is_some());
+        assert_eq!(fuzzy_score("kq", "Quick Sort"), None);
+        assert_eq!(fuzzy_score("heap", "Quick Sort"), None);
+    }
+
+    #[test]
+    fn fuzzy_score_ignores_case_and_accepts_an_empty_query() {
+        assert_eq!(
+            fuzzy_score("QUICK", "quick sort"),
+            fuzzy_score("quick", "Quick Sort")
+        );
+        assert_eq!(fuzzy_score("", "anything"), Some(0));
+        assert_eq!(fuzzy_score("   ", "anything"), Some(0));
+    }
+
+    #[test]
+    fn fuzzy_score_prefers_runs_and_word_starts() {
+        let run = fuzzy_score("sort", "theme sort").unwrap();
+        let scattered = fuzzy_score("sort", "show our rt").unwrap();
+        assert!(run > scattered);
+
+        let word_start = fuzzy_score("m", "merge").unwrap();
+        let inside = fuzzy_score("m", "theme").unwrap();
+        assert!(word_start > inside);
+    }
+
+    #[test]
+    fn matches_rank_the_best_score_first() {
+        let entries: Vec<PaletteEntry> = ["Selection Sort", "Shuffle the bars", "Save settings"]
+            .into_iter()
+            .map(|label| PaletteEntry {
+                kind: "action",
+                label: label.to_string(),
+                hint: String::new(),
+                command: Command::SaveOnExit(true),
+            })
+            .collect();
+        let palette = CommandPalette {
+            query: "shuf".to_string(),
+            selected: 0,
+        };
+
+        let labels: Vec<&str> = palette
+            .matches(&entries)
+            .into_iter()
+            .map(|entry| entry.label.as_str())
+            .collect();
+        assert_eq!(labels.first(), Some(&"Shuffle the bars"));
+        assert!(!labels.contains(&"Save settings"));
+    }
+}
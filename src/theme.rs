@@ -0,0 +1,145 @@
+use ratatui::style::Color;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Theme {
+    pub name: &'static str,
+    pub text: Color,
+    pub dim: Color,
+    pub muted: Color,
+    pub accent: Color,
+    pub highlight: Color,
+    pub good: Color,
+    pub bad: Color,
+    pub special: Color,
+    pub inverse: Color,
+    pub duplicates: [Color; 8],
+}
+
+pub const THEMES: [Theme; 5] = [
+    Theme {
+        name: "default",
+        text: Color::White,
+        dim: Color::Gray,
+        muted: Color::DarkGray,
+        accent: Color::Cyan,
+        highlight: Color::Yellow,
+        good: Color::Green,
+        bad: Color::Red,
+        special: Color::Magenta,
+        inverse: Color::Black,
+        duplicates: [
+            Color::Cyan,
+            Color::Magenta,
+            Color::Yellow,
+            Color::Green,
+            Color::Blue,
+            Color::Red,
+            Color::LightCyan,
+            Color::LightMagenta,
+        ],
+    },
+    Theme {
+        name: "light",
+        text: Color::Black,
+        dim: Color::DarkGray,
+        muted: Color::Gray,
+        accent: Color::Indexed(25),
+        highlight: Color::Indexed(130),
+        good: Color::Indexed(28),
+        bad: Color::Indexed(160),
+        special: Color::Indexed(90),
+        inverse: Color::White,
+        duplicates: [
+            Color::Indexed(25),
+            Color::Indexed(90),
+            Color::Indexed(130),
+            Color::Indexed(28),
+            Color::Indexed(160),
+            Color::Indexed(30),
+            Color::Indexed(94),
+            Color::Indexed(55),
+        ],
+    },
+    Theme {
+        name: "high-contrast",
+        text: Color::White,
+        dim: Color::White,
+        muted: Color::Gray,
+        accent: Color::LightCyan,
+        highlight: Color::LightYellow,
+        good: Color::LightGreen,
+        bad: Color::LightRed,
+        special: Color::LightMagenta,
+        inverse: Color::Black,
+        duplicates: [
+            Color::LightCyan,
+            Color::LightMagenta,
+            Color::LightYellow,
+            Color::LightGreen,
+            Color::LightBlue,
+            Color::LightRed,
+            Color::White,
+            Color::Gray,
+        ],
+    },
+    Theme {
+        name: "deuteranopia",
+        text: Color::White,
+        dim: Color::Gray,
+        muted: Color::DarkGray,
+        accent: Color::Rgb(86, 180, 233),
+        highlight: Color::Rgb(240, 228, 66),
+        good: Color::Rgb(0, 114, 178),
+        bad: Color::Rgb(230, 159, 0),
+        special: Color::Rgb(204, 121, 167),
+        inverse: Color::Black,
+        duplicates: [
+            Color::Rgb(86, 180, 233),
+            Color::Rgb(230, 159, 0),
+            Color::Rgb(0, 158, 115),
+            Color::Rgb(240, 228, 66),
+            Color::Rgb(0, 114, 178),
+            Color::Rgb(213, 94, 0),
+            Color::Rgb(204, 121, 167),
+            Color::White,
+        ],
+    },
+    Theme {
+        name: "monochrome",
+        text: Color::Gray,
+        dim: Color::Gray,
+        muted: Color::Indexed(240),
+        accent: Color::White,
+        highlight: Color::Indexed(253),
+        good: Color::Indexed(243),
+        bad: Color::Indexed(248),
+        special: Color::Indexed(246),
+        inverse: Color::Black,
+        duplicates: [
+            Color::White,
+            Color::Gray,
+            Color::DarkGray,
+            Color::White,
+            Color::Gray,
+            Color::DarkGray,
+            Color::White,
+            Color::Gray,
+        ],
+    },
+];
+
+impl Theme {
+    pub fn by_name(name: &str) -> Option<&'static Theme> {
+        THEMES
+            .iter()
+            .find(|theme| theme.name.eq_ignore_ascii_case(name))
+    }
+
+    pub fn next(&self) -> &'static Theme {
+        let index = THEMES
+            .iter()
+            .position(|theme| theme.name == self.name)
+            .unwrap_or(0);
+        &THEMES[(index + 1) % THEMES.len()]
+    }
+}
@@ -1,109 +1,498 @@
-use crate::app::{App, AppStatus, MIN_BARS_SIZE};
+use crate::algorithms::dp::{DpCell, DpTable};
+use crate::algorithms::hashing::HashTableView;
+use crate::algorithms::matching::TextMatch;
+use crate::algorithms::{AlgorithmCategory, AlgorithmType, ArrayView, BarState, Visualization};
+use crate::app::{
+    App, AppStatus, ChartStyle, InputPrompt, MIN_BARS_SIZE, MIN_VIEWPORT, PromptKind,
+};
+use crate::keymap::{Action, HELP_GROUPS, HelpGroup, HelpLine};
+use crate::palette::CommandPalette;
+use crate::theme::Theme;
 use ratatui::{
     buffer::Buffer,
-    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    layout::{Alignment, Constraint, Direction, Layout, Position, Rect},
     style::{Color, Modifier, Style},
     text::{Line, Span},
-    widgets::{BarChart, Block, BorderType, Paragraph, Widget, Wrap},
+    widgets::{
+        Bar, BarChart, BarGroup, Block, BorderType, Cell, Clear, Padding, Paragraph, Row, Table,
+        Widget, Wrap,
+    },
 };
+use std::ops::Range;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Hit {
+    Category,
+    Algorithm(usize),
+    Bar(usize),
+    Timeline(usize),
+    Log,
+    Popup,
+}
+
+enum PickerRow {
+    Category(AlgorithmCategory),
+    Algorithm(usize),
+}
+
+struct PickerLayout {
+    popup: Rect,
+    list: Rect,
+    details: Rect,
+    start: usize,
+}
+
+struct Regions {
+    tabs: Rect,
+    visualization: Rect,
+    log: Rect,
+    timeline: Rect,
+    footer: Rect,
+}
 
 impl Widget for &App {
     fn render(self, area: Rect, buf: &mut Buffer) {
+        if self.is_too_small() {
+            self.render_too_small(area, buf);
+            return;
+        }
+
         let tiny = self.is_tiny_layout();
         let compact = self.is_compact_layout();
+        let regions = self.regions(area);
 
-        let bottom_percent = if tiny {
-            40
-        } else if compact {
-            30
-        } else {
-            20
-        };
-
-        let main_chunks = Layout::default()
-            .direction(Direction::Vertical)
-            .constraints([
-                Constraint::Percentage(100 - bottom_percent),
-                Constraint::Percentage(bottom_percent),
-            ])
-            .split(area);
-
-        self.render_visualization(main_chunks[0], buf);
+        self.render_tabs(regions.tabs, buf);
+        self.render_visualization(regions.visualization, buf);
+        if !regions.log.is_empty() {
+            self.render_log(regions.log, buf);
+        }
+        self.render_timeline(regions.timeline, buf);
 
         if tiny {
-            self.render_compact_footer(main_chunks[1], buf);
+            self.render_compact_footer(regions.footer, buf);
         } else if compact {
             let footer_chunks = Layout::default()
                 .direction(Direction::Vertical)
                 .constraints([Constraint::Min(4), Constraint::Min(3)])
-                .split(main_chunks[1]);
+                .split(regions.footer);
 
             self.render_controls(footer_chunks[0], buf, true);
             self.render_info(footer_chunks[1], buf, true);
         } else {
+            let has_pseudocode = !self.get_current_algorithm().pseudocode().is_empty();
+            let constraints = if has_pseudocode {
+                vec![
+                    Constraint::Percentage(30),
+                    Constraint::Percentage(35),
+                    Constraint::Percentage(35),
+                ]
+            } else {
+                vec![Constraint::Percentage(50), Constraint::Percentage(50)]
+            };
             let footer_chunks = Layout::default()
                 .direction(Direction::Horizontal)
-                .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
-                .split(main_chunks[1]);
+                .constraints(constraints)
+                .split(regions.footer);
 
             self.render_controls(footer_chunks[0], buf, false);
             self.render_info(footer_chunks[1], buf, false);
+            if has_pseudocode {
+                self.render_pseudocode(footer_chunks[2], buf);
+            }
+        }
+
+        if let Some(prompt) = &self.input_prompt {
+            self.render_input_prompt(prompt, area, buf);
+        }
+        if let Some(highlighted) = self.picker {
+            self.render_picker(highlighted, area, buf);
+        }
+        if let Some(palette) = &self.palette {
+            self.render_palette(palette, area, buf);
+        }
+        if let Some(offset) = self.help {
+            self.render_help(offset, area, buf);
         }
     }
 }
 
 impl App {
+    fn regions(&self, area: Rect) -> Regions {
+        let bottom_percent = if self.is_tiny_layout() {
+            40
+        } else if self.is_compact_layout() || self.show_card {
+            30
+        } else {
+            20
+        };
+
+        let [main, footer] = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Percentage(100 - bottom_percent),
+                Constraint::Percentage(bottom_percent),
+            ])
+            .areas(area);
+        let [tabs, main, timeline] = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(1),
+                Constraint::Min(0),
+                Constraint::Length(1),
+            ])
+            .areas(main);
+        let [visualization, log] = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Min(0), Constraint::Length(self.log_width())])
+            .areas(main);
+
+        Regions {
+            tabs,
+            visualization,
+            log,
+            timeline,
+            footer,
+        }
+    }
+
+    pub fn hit_test(&self, column: u16, row: u16) -> Option<Hit> {
+        if self.is_too_small() {
+            return None;
+        }
+
+        let regions = self.regions(self.viewport());
+        let position = Position::new(column, row);
+
+        if regions.tabs.contains(position) {
+            let mut x = regions.tabs.x;
+            for (span, hit) in self.tab_spans() {
+                x += span.width() as u16;
+                if column < x {
+                    return hit;
+                }
+            }
+            None
+        } else if regions.timeline.contains(position) {
+            self.timeline_step(column).map(Hit::Timeline)
+        } else if regions.log.contains(position) {
+            Some(Hit::Log)
+        } else if regions.visualization.contains(position) {
+            self.bar_at(Block::bordered().inner(regions.visualization), position)
+                .map(Hit::Bar)
+        } else {
+            None
+        }
+    }
+
+    pub fn timeline_step(&self, column: u16) -> Option<usize> {
+        let (track, total) = self.timeline_track(self.regions(self.viewport()).timeline)?;
+        let offset = column.clamp(track.x, track.right() - 1) - track.x;
+        let last = (track.width - 1).max(1) as usize;
+        Some((offset as usize * total + last / 2) / last)
+    }
+
+    fn viewport(&self) -> Rect {
+        Rect::new(0, 0, self.viewport_width, self.viewport_height)
+    }
+
+    fn bar_at(&self, area: Rect, position: Position) -> Option<usize> {
+        let Some(Visualization::Array(view)) = self.get_current_algorithm().visualization() else {
+            return None;
+        };
+        if !area.contains(position) {
+            return None;
+        }
+
+        let len = view.elements.len();
+        match self.chart {
+            ChartStyle::Disparity => return None,
+            ChartStyle::Horizontal => {
+                let index = (position.y - area.y) as usize * len / area.height as usize;
+                return (index < len).then_some(index);
+            }
+            _ => {}
+        }
+
+        let offset = position.x - area.x;
+        if self.is_downsampled(len)
+            || matches!(self.chart, ChartStyle::Scatter | ChartStyle::Gradient)
+        {
+            let index = offset as usize * len / area.width as usize;
+            return (index < len).then_some(index);
+        }
+
+        let (bar_width, bar_gap) = self.bar_chart_layout(&view);
+        let (stride, scale) = (bar_width + bar_gap, self.columns_per_cell() as u16);
+        (offset * scale..(offset + 1) * scale)
+            .find(|unit| unit % stride < bar_width)
+            .map(|unit| (unit / stride) as usize)
+            .filter(|&index| index < len)
+    }
+
+    fn tab_spans(&self) -> Vec<(Span<'static>, Option<Hit>)> {
+        let theme = self.theme;
+        let mut spans = vec![(
+            Span::styled(
+                format!(" {} ▸ ", self.current_category().name()),
+                Style::default()
+                    .fg(theme.accent)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Some(Hit::Category),
+        )];
+
+        let keys = self.keymap.keys(Action::SelectAlgorithm);
+        let indices = self.algorithms_in_category(self.current_category());
+        for (position, index) in indices.into_iter().enumerate() {
+            let name_style = if index == self.current_algorithm {
+                Style::default()
+                    .fg(theme.inverse)
+                    .bg(theme.accent)
+                    .add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(theme.text)
+            };
+            if let Some(key) = keys.get(position) {
+                spans.push((
+                    Span::styled(format!(" {key}"), key_style(theme)),
+                    Some(Hit::Algorithm(index)),
+                ));
+            }
+            spans.push((
+                Span::styled(
+                    format!(" {} ", self.algorithms[index].short_name()),
+                    name_style,
+                ),
+                Some(Hit::Algorithm(index)),
+            ));
+            spans.push((Span::raw(" "), None));
+        }
+
+        let width: usize = spans.iter().map(|(span, _)| span.width()).sum();
+        if width > self.viewport_width as usize {
+            spans[0].0.content = " ▸ ".into();
+        }
+        spans
+    }
+
+    fn render_tabs(&self, area: Rect, buf: &mut Buffer) {
+        let spans: Vec<Span> = self.tab_spans().into_iter().map(|(span, _)| span).collect();
+        Line::from(spans).render(area, buf);
+    }
+
+    fn timeline_track(&self, area: Rect) -> Option<(Rect, usize)> {
+        let total = self.total_steps?;
+        let label_width = self.timeline_label(total).chars().count() as u16;
+        let track = Rect {
+            x: area.x + label_width,
+            width: area.width.saturating_sub(label_width + 1),
+            ..area
+        };
+        (track.width > 1).then_some((track, total.max(1)))
+    }
+
+    fn timeline_label(&self, total: usize) -> String {
+        let digits = total.to_string().len();
+        format!(" step {:>digits$}/{total} ", self.steps)
+    }
+
+    fn render_timeline(&self, area: Rect, buf: &mut Buffer) {
+        let theme = self.theme;
+        let Some((track, total)) = self.timeline_track(area) else {
+            Line::styled(
+                format!(" step {}", self.steps),
+                Style::default().fg(theme.muted),
+            )
+            .render(area, buf);
+            return;
+        };
+
+        Line::styled(self.timeline_label(total), Style::default().fg(theme.dim)).render(area, buf);
+
+        let head = track.x + (self.steps.min(total) * (track.width - 1) as usize / total) as u16;
+        for x in track.left()..track.right() {
+            let (symbol, color) = match x.cmp(&head) {
+                std::cmp::Ordering::Less => ("━", theme.accent),
+                std::cmp::Ordering::Equal => ("●", theme.highlight),
+                std::cmp::Ordering::Greater => ("─", theme.muted),
+            };
+            buf[(x, track.y)].set_symbol(symbol).set_fg(color);
+        }
+    }
+
     fn render_visualization(&self, area: Rect, buf: &mut Buffer) {
-        let max_bars = self.max_bars();
-        let block = Block::bordered()
-            .title(format!(
-                "Algorithm Visualization (max {} bars)",
-                max_bars
-            ))
+        let theme = self.theme;
+        let current_algo = self.get_current_algorithm();
+        let Some(visualization) = self.visualization() else {
+            return;
+        };
+
+        let title = match visualization {
+            Visualization::Array(view) => {
+                let selected = self
+                    .selected_bar
+                    .and_then(|index| Some((index, view.elements.get(index)?.value)));
+                let len = view.elements.len();
+                let mut title =
+                    if matches!(self.chart, ChartStyle::Horizontal | ChartStyle::Disparity) {
+                        format!("Algorithm Visualization ({len} bars)")
+                    } else if self.is_downsampled(len) {
+                        format!(
+                            "Algorithm Visualization ({len} bars, ~{} per column)",
+                            len.div_ceil(self.chart_inner_width().max(1) as usize)
+                        )
+                    } else {
+                        format!("Algorithm Visualization (max {} bars)", self.max_bars())
+                    };
+                if self.chart != ChartStyle::Bars {
+                    title.push_str(&format!(" · {}", self.chart.name()));
+                }
+                if let Some((index, value)) = selected {
+                    title.push_str(&format!(" · bars[{index}] = {value}"));
+                }
+                if self.show_values
+                    && matches!(self.chart, ChartStyle::Bars | ChartStyle::HalfBars)
+                    && ((self.bar_chart_layout(&view).0 as usize) / self.columns_per_cell())
+                        < view.label_width()
+                {
+                    title.push_str(" · too many bars for labels");
+                }
+                title
+            }
+            Visualization::Table(_) => format!(
+                "{} Table · {}",
+                current_algo.category().name(),
+                current_algo.name()
+            ),
+            Visualization::Text(_) => format!(
+                "{} · {}",
+                current_algo.category().name(),
+                current_algo.name()
+            ),
+            Visualization::HashTable(view) => format!(
+                "Hash Table · {} (capacity {})",
+                current_algo.name(),
+                view.stats.capacity
+            ),
+        };
+        let mut block = Block::bordered()
+            .title(title)
             .title_alignment(Alignment::Center)
             .border_type(BorderType::Rounded);
+        if let Visualization::Array(_) = visualization {
+            block = block.title_bottom(bar_legend(theme));
+        }
 
         let inner = block.inner(area);
         block.render(area, buf);
 
-        let current_algo = self.get_current_algorithm();
-        let bars = current_algo.get_data();
-        let current_indices = current_algo.get_current_indices();
-        let comparisons = current_algo.get_comparisons();
+        match visualization {
+            Visualization::Array(view) => self.render_bars(&view, inner, buf),
+            Visualization::Table(table) => render_table(theme, table, inner, buf),
+            Visualization::Text(text_match) => render_text_match(theme, text_match, inner, buf),
+            Visualization::HashTable(hash_table) => {
+                render_hash_table(theme, hash_table, inner, buf)
+            }
+        }
+    }
 
-        if bars.is_empty() {
+    fn render_bars(&self, view: &ArrayView, area: Rect, buf: &mut Buffer) {
+        let theme = self.theme;
+        if view.elements.is_empty() {
             return;
         }
+        let len = view.elements.len();
+        let downsampled = self.is_downsampled(len);
+        match self.chart {
+            ChartStyle::Horizontal => {
+                return render_horizontal(theme, view, self.selected_bar, area, buf);
+            }
+            ChartStyle::Disparity if !downsampled => {
+                return render_disparity(theme, view, &self.sorted_values, area, buf);
+            }
+            _ => {}
+        }
 
-        let max_value = *bars.iter().max().unwrap_or(&1) as u64;
-        let (bar_width, bar_gap) = self.bar_chart_layout(bars.len());
-        let bar_data: Vec<(&str, u64)> = bars
-            .iter()
-            .enumerate()
-            .map(|(i, &value)| {
-                let label = if current_indices.contains(&i) {
-                    "●"
-                } else if comparisons.iter().any(|(a, b)| *a == i || *b == i) {
-                    "◐"
-                } else {
-                    " "
-                };
-                (label, value as u64)
-            })
-            .collect();
+        let [chart_area, overlay_area] = if area.height > 4 {
+            Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([Constraint::Min(1), Constraint::Length(1)])
+                .areas(area)
+        } else {
+            [area, Rect::default()]
+        };
+        let columns = chart_area.width as usize;
+        let (bar_width, bar_gap) = self.bar_chart_layout(view);
+        let scale = self.columns_per_cell();
+        let spans = |index: usize| {
+            let Range { start, end } = if downsampled
+                || matches!(self.chart, ChartStyle::Scatter | ChartStyle::Gradient)
+            {
+                slot(index, len, columns)
+            } else {
+                let left = index * (bar_width + bar_gap) as usize;
+                left / scale..(left + bar_width as usize).div_ceil(scale)
+            };
+            area.x + start.min(columns) as u16..area.x + end.min(columns) as u16
+        };
+
+        match self.chart {
+            ChartStyle::Gradient => render_gradient(theme, view, chart_area, buf),
+            _ if downsampled => render_downsampled(theme, view, chart_area, buf),
+            ChartStyle::Scatter => render_scatter(theme, view, chart_area, buf),
+            _ => {
+                let ranks = self.has_duplicates().then(|| view.duplicate_ranks());
+                let bars: Vec<(i32, Color)> = view
+                    .elements
+                    .iter()
+                    .enumerate()
+                    .map(|(i, element)| {
+                        let state = view.bar_state(i);
+                        let color = match &ranks {
+                            Some(ranks)
+                                if matches!(
+                                    state,
+                                    BarState::Idle | BarState::Outside | BarState::Sorted
+                                ) =>
+                            {
+                                theme.duplicates[ranks[i] % theme.duplicates.len()]
+                            }
+                            _ => bar_color(theme, state),
+                        };
 
-        let bar_chart = BarChart::default()
-            .data(&bar_data)
-            .max(max_value)
-            .bar_width(bar_width)
-            .bar_gap(bar_gap)
-            .bar_style(Style::default().fg(Color::White))
-            .value_style(Style::default().fg(Color::White).bg(Color::White));
+                        (element.value, color)
+                    })
+                    .collect();
 
-        bar_chart.render(inner, buf);
+                let (cells, gap) = (bar_width as usize / scale, bar_gap as usize / scale);
+                if self.chart == ChartStyle::Dots {
+                    render_dots(theme, &bars, view.highlighted(), &spans, chart_area, buf);
+                } else if (cells * scale, gap * scale) == (bar_width as usize, bar_gap as usize) {
+                    render_signed_bars(
+                        &bars,
+                        cells as u16,
+                        gap as u16,
+                        self.show_values && cells >= view.label_width(),
+                        chart_area,
+                        buf,
+                    );
+                } else {
+                    render_half_bars(
+                        &bars,
+                        |index| highlight_priority(view.bar_state(index)),
+                        bar_width,
+                        bar_gap,
+                        chart_area,
+                        buf,
+                    );
+                }
+            }
+        }
+        render_range_overlay(theme, view, self.selected_bar, spans, overlay_area, buf);
     }
 
     fn render_controls(&self, area: Rect, buf: &mut Buffer, compact: bool) {
+        let theme = self.theme;
         let block = Block::bordered()
             .title("Controls")
             .title_alignment(Alignment::Center)
@@ -111,41 +500,24 @@ impl App {
 
         let controls = if compact {
             vec![
-                Line::from(vec![
-                    Span::styled("Space", key_style()),
-                    Span::raw(" Play  "),
-                    Span::styled("R", key_style()),
-                    Span::raw(" Reset  "),
-                    Span::styled("S", key_style()),
-                    Span::raw(" Shuffle  "),
-                    Span::styled("↑↓", key_style()),
-                    Span::raw(" Speed  "),
-                    Span::styled("←→", key_style()),
-                    Span::raw(" Bars"),
-                ]),
-                Line::from(vec![
-                    Span::styled("1", key_style()),
-                    Span::raw(" Bubble  "),
-                    Span::styled("2", key_style()),
-                    Span::raw(" Quick  "),
-                    Span::styled("3", key_style()),
-                    Span::raw(" Merge  "),
-                    Span::styled("4", key_style()),
-                    Span::raw(" Selection  "),
-                    Span::styled("Q", key_style()),
-                    Span::raw(" Quit"),
-                ]),
+                Line::from(self.help_spans(HelpLine::Playback, "  ")),
+                Line::from(self.help_spans(HelpLine::Navigation, "  ")),
             ]
         } else {
-            vec![
-                control_line("Space", "Start/Pause"),
-                control_line("R", "Reset"),
-                control_line("S", "Shuffle & Reset"),
-                control_line("↑/↓", "Speed Up/Down"),
-                control_line("Q/Esc", "Quit"),
-                control_line("←/→", "Decrease/Increase bars"),
-                control_line("1/2/3/4", "Bubble / Quick / Merge / Selection"),
-            ]
+            let algorithms = self.algorithms_in_category(self.current_category()).len();
+            let mut lines = Vec::new();
+            for line in [HelpLine::Playback, HelpLine::Navigation] {
+                if line == HelpLine::Navigation
+                    && let Some(keys) = self.keymap.range_label(Action::SelectAlgorithm, algorithms)
+                {
+                    lines.push(control_line(theme, &keys, "Select algorithm"));
+                }
+                for group in self.help_groups(line) {
+                    let keys = self.keymap.group_label(group, true);
+                    lines.push(control_line(theme, &keys, group.description));
+                }
+            }
+            lines
         };
 
         Paragraph::new(controls)
@@ -155,6 +527,7 @@ impl App {
     }
 
     fn render_info(&self, area: Rect, buf: &mut Buffer, compact: bool) {
+        let theme = self.theme;
         let block = Block::bordered()
             .title("Information")
             .title_alignment(Alignment::Center)
@@ -168,55 +541,230 @@ impl App {
 
         let current_algo = self.get_current_algorithm();
         let status_color = match self.app_status {
-            AppStatus::Running => Color::Yellow,
-            AppStatus::Paused => Color::Red,
-            AppStatus::Completed => Color::Green,
+            AppStatus::Running => theme.highlight,
+            AppStatus::Paused => theme.bad,
+            AppStatus::Completed => theme.good,
         };
 
+        let input = current_algo.input();
+
         let info = if compact {
-            vec![Line::from(vec![
+            let mut spans = vec![
                 Span::raw("Algo: "),
-                Span::styled(current_algo.name(), Style::default().fg(Color::Cyan)),
-                Span::raw("  Bars: "),
-                Span::styled(format!("{}", self.bars.len()), Style::default().fg(Color::Cyan)),
-                Span::raw("/"),
-                Span::styled(format!("{}", self.max_bars()), Style::default().fg(Color::DarkGray)),
+                Span::styled(current_algo.name(), Style::default().fg(theme.accent)),
+                Span::raw(" "),
+                Span::styled(
+                    current_algo.info().average,
+                    Style::default().fg(theme.muted),
+                ),
+            ];
+            if let Some(input) = &input {
+                spans.push(Span::raw("  Input: "));
+                spans.push(Span::styled(
+                    input.clone(),
+                    Style::default().fg(theme.accent),
+                ));
+            } else {
+                spans.push(Span::raw("  Bars: "));
+                spans.push(Span::styled(
+                    format!("{}", self.bars.len()),
+                    Style::default().fg(theme.accent),
+                ));
+                if !self.large_arrays {
+                    spans.push(Span::raw("/"));
+                    spans.push(Span::styled(
+                        format!("{}", self.max_bars()),
+                        Style::default().fg(theme.muted),
+                    ));
+                }
+            }
+            for (verdict, color) in [self.stability_verdict(), self.verification_verdict()]
+                .into_iter()
+                .flatten()
+            {
+                spans.push(Span::raw("  "));
+                spans.push(Span::styled(verdict, Style::default().fg(color)));
+            }
+            if let Some(Visualization::Array(_)) = current_algo.visualization() {
+                spans.push(Span::raw("  Cmp/Swp: "));
+                spans.push(Span::styled(
+                    format!("{}/{}", self.counts.comparisons, self.counts.swaps),
+                    Style::default().fg(theme.accent),
+                ));
+            }
+            if let Some(Visualization::HashTable(view)) = current_algo.visualization() {
+                spans.push(Span::raw("  Load: "));
+                spans.push(Span::styled(
+                    format!("{:.2}", view.stats.load_factor()),
+                    Style::default().fg(theme.accent),
+                ));
+            }
+            spans.extend([
                 Span::raw("  Status: "),
                 Span::styled(status, Style::default().fg(status_color)),
                 Span::raw("  Speed: "),
-                Span::styled(self.speed_label(), Style::default().fg(Color::Yellow)),
-            ])]
+                Span::styled(self.speed_label(), Style::default().fg(theme.highlight)),
+            ]);
+
+            vec![Line::from(spans)]
         } else {
-            vec![
-                Line::from(vec![
-                    Span::raw("Algorithm: "),
-                    Span::styled(current_algo.name(), Style::default().fg(Color::Cyan)),
+            let size_line = match &input {
+                Some(input) => Line::from(vec![
+                    Span::raw("Input: "),
+                    Span::styled(input.clone(), Style::default().fg(theme.accent)),
                 ]),
-                Line::from(vec![
+                None if self.large_arrays => Line::from(vec![
+                    Span::raw("Bars: "),
+                    Span::styled(
+                        format!("{}", self.bars.len()),
+                        Style::default().fg(theme.accent),
+                    ),
+                    Span::raw(" (large arrays)"),
+                ]),
+                None => Line::from(vec![
                     Span::raw("Bars: "),
-                    Span::styled(format!("{}", self.bars.len()), Style::default().fg(Color::Cyan)),
+                    Span::styled(
+                        format!("{}", self.bars.len()),
+                        Style::default().fg(theme.accent),
+                    ),
                     Span::raw(" (max "),
-                    Span::styled(format!("{}", self.max_bars()), Style::default().fg(Color::Cyan)),
+                    Span::styled(
+                        format!("{}", self.max_bars()),
+                        Style::default().fg(theme.accent),
+                    ),
                     Span::raw(", min "),
-                    Span::styled(format!("{MIN_BARS_SIZE}"), Style::default().fg(Color::Cyan)),
+                    Span::styled(
+                        format!("{MIN_BARS_SIZE}"),
+                        Style::default().fg(theme.accent),
+                    ),
                     Span::raw(")"),
                 ]),
-                Line::from(vec![
-                    Span::raw("Status: "),
-                    Span::styled(status, Style::default().fg(status_color)),
-                ]),
+            };
+
+            let mut header = vec![
+                Span::styled(if self.show_card { "▾ " } else { "▸ " }, key_style(theme)),
+                Span::styled(current_algo.name(), Style::default().fg(theme.accent)),
+                Span::raw(" ("),
+                Span::styled(
+                    current_algo.category().name(),
+                    Style::default().fg(theme.muted),
+                ),
+                Span::raw(")"),
+            ];
+            let mut card = Vec::new();
+            let mut lines = if self.show_card {
+                card = info_card(theme, current_algo);
+                let description = card.pop();
+                let mut lines = vec![Line::from(header)];
+                lines.append(&mut card);
+                card.extend(description);
+                lines
+            } else {
+                header.extend([
+                    Span::raw(" · "),
+                    Span::styled(
+                        current_algo.info().average,
+                        Style::default().fg(theme.highlight),
+                    ),
+                ]);
+                vec![Line::from(header)]
+            };
+            lines.push(size_line);
+            if let Some((verdict, color)) = self.stability_verdict() {
+                lines.push(Line::from(vec![
+                    Span::raw("Keys: "),
+                    Span::styled(
+                        if self.custom_bars {
+                            "Custom"
+                        } else {
+                            self.distribution.name()
+                        },
+                        Style::default().fg(theme.accent),
+                    ),
+                    Span::raw(" · "),
+                    Span::styled(verdict, Style::default().fg(color)),
+                ]));
+            }
+            if let Some(Visualization::Array(_)) = current_algo.visualization() {
+                lines.push(Line::from(vec![
+                    Span::raw("Comparisons: "),
+                    Span::styled(
+                        format!("{}", self.counts.comparisons),
+                        Style::default().fg(theme.accent),
+                    ),
+                    Span::raw("  Swaps: "),
+                    Span::styled(
+                        format!("{}", self.counts.swaps),
+                        Style::default().fg(theme.accent),
+                    ),
+                    Span::raw("  Reads: "),
+                    Span::styled(
+                        format!("{}", self.counts.reads),
+                        Style::default().fg(theme.accent),
+                    ),
+                    Span::raw("  Writes: "),
+                    Span::styled(
+                        format!("{}", self.counts.writes),
+                        Style::default().fg(theme.accent),
+                    ),
+                ]));
+            }
+            if let Some(Visualization::HashTable(view)) = current_algo.visualization() {
+                lines.push(Line::from(vec![
+                    Span::raw("Load: "),
+                    Span::styled(
+                        format!("{:.2}", view.stats.load_factor()),
+                        Style::default().fg(theme.accent),
+                    ),
+                    Span::raw("  Probes: "),
+                    Span::styled(
+                        format!(
+                            "{:.2} avg, {} max",
+                            view.stats.average_probe(),
+                            view.stats.longest_probe
+                        ),
+                        Style::default().fg(theme.accent),
+                    ),
+                    Span::raw("  Collisions: "),
+                    Span::styled(
+                        format!("{}", view.stats.collisions),
+                        Style::default().fg(theme.accent),
+                    ),
+                    Span::raw("  Resizes: "),
+                    Span::styled(
+                        format!("{}", view.stats.resizes),
+                        Style::default().fg(theme.accent),
+                    ),
+                ]));
+            }
+            let mut status_line = vec![
+                Span::raw("Status: "),
+                Span::styled(status, Style::default().fg(status_color)),
+            ];
+            if let Some((verdict, color)) = self.verification_verdict() {
+                status_line.extend([
+                    Span::raw(" · "),
+                    Span::styled(verdict, Style::default().fg(color)),
+                ]);
+            }
+            lines.extend([
+                Line::from(status_line),
                 Line::from(vec![
                     Span::raw("Speed: "),
-                    Span::styled(self.speed_label(), Style::default().fg(Color::Yellow)),
+                    Span::styled(self.speed_label(), Style::default().fg(theme.highlight)),
+                    Span::raw("  Theme: "),
+                    Span::styled(theme.name, Style::default().fg(theme.accent)),
                 ]),
                 Line::from(vec![
                     Span::raw("Terminal: "),
                     Span::styled(
                         format!("{}×{}", self.viewport_width, self.viewport_height),
-                        Style::default().fg(Color::DarkGray),
+                        Style::default().fg(theme.muted),
                     ),
                 ]),
-            ]
+            ]);
+            lines.extend(card);
+            lines
         };
 
         Paragraph::new(info)
@@ -225,7 +773,78 @@ impl App {
             .render(area, buf);
     }
 
+    fn render_pseudocode(&self, area: Rect, buf: &mut Buffer) {
+        let theme = self.theme;
+        let block = Block::bordered()
+            .title("Pseudocode")
+            .title_alignment(Alignment::Center)
+            .border_type(BorderType::Rounded);
+
+        let current_algo = self.get_current_algorithm();
+        let code = current_algo.pseudocode();
+        let current = current_algo.code_line();
+        let visible = block.inner(area).height as usize;
+        let start = window_start(current.unwrap_or(0), visible, code.len());
+
+        let lines: Vec<Line> = code
+            .iter()
+            .enumerate()
+            .skip(start)
+            .take(visible)
+            .map(|(index, text)| {
+                if current == Some(index) {
+                    Line::from(vec![
+                        Span::styled("▶ ", key_style(theme)),
+                        Span::styled(text.to_string(), key_style(theme)),
+                    ])
+                } else {
+                    Line::styled(format!("  {text}"), Style::default().fg(theme.dim))
+                }
+            })
+            .collect();
+
+        Paragraph::new(lines)
+            .block(block)
+            .wrap(Wrap { trim: false })
+            .render(area, buf);
+    }
+
+    fn render_log(&self, area: Rect, buf: &mut Buffer) {
+        let theme = self.theme;
+        let title = match &self.log.notice {
+            Some(notice) => format!("Log · {notice}"),
+            None if self.log.is_following() => format!("Log ({})", self.log.len()),
+            None => format!("Log ({}, scrolled)", self.log.len()),
+        };
+        let block = Block::bordered()
+            .title(title)
+            .title_alignment(Alignment::Center)
+            .border_type(BorderType::Rounded);
+
+        let inner = block.inner(area);
+        let visible = inner.height as usize;
+        let width = (inner.width as usize).max(1);
+        let wrapped: Vec<String> = self
+            .log
+            .visible(visible)
+            .flat_map(|entry| {
+                let chars: Vec<char> = entry.chars().collect();
+                chars
+                    .chunks(width)
+                    .map(|chunk| chunk.iter().collect())
+                    .collect::<Vec<String>>()
+            })
+            .collect();
+        let lines: Vec<Line> = wrapped[wrapped.len().saturating_sub(visible)..]
+            .iter()
+            .map(|text| Line::styled(text.clone(), Style::default().fg(theme.dim)))
+            .collect();
+
+        Paragraph::new(lines).block(block).render(area, buf);
+    }
+
     fn render_compact_footer(&self, area: Rect, buf: &mut Buffer) {
+        let theme = self.theme;
         let block = Block::bordered()
             .title("Controls & Info")
             .title_alignment(Alignment::Center)
@@ -238,43 +857,65 @@ impl App {
         };
 
         let current_algo = self.get_current_algorithm();
-        let lines = vec![
-            Line::from(vec![
-                Span::styled(current_algo.name(), Style::default().fg(Color::Cyan)),
-                Span::raw(" | "),
+        let takes_input = current_algo.input().is_some();
+
+        let mut summary = vec![
+            Span::styled(current_algo.short_name(), Style::default().fg(theme.accent)),
+            Span::raw(" | "),
+        ];
+        if !takes_input {
+            summary.extend([
                 Span::raw("Bars "),
-                Span::styled(format!("{}", self.bars.len()), Style::default().fg(Color::Cyan)),
-                Span::raw("/"),
-                Span::styled(format!("{}", self.max_bars()), Style::default().fg(Color::DarkGray)),
+                Span::styled(
+                    format!("{}", self.bars.len()),
+                    Style::default().fg(theme.accent),
+                ),
+            ]);
+            if !self.large_arrays {
+                summary.extend([
+                    Span::raw("/"),
+                    Span::styled(
+                        format!("{}", self.max_bars()),
+                        Style::default().fg(theme.muted),
+                    ),
+                ]);
+            }
+            summary.push(Span::raw(" | "));
+        }
+        for (verdict, color) in [self.stability_verdict(), self.verification_verdict()]
+            .into_iter()
+            .flatten()
+        {
+            summary.extend([
+                Span::styled(verdict, Style::default().fg(color)),
                 Span::raw(" | "),
-                Span::styled(status, Style::default().fg(Color::Yellow)),
-                Span::raw(" | Speed "),
-                Span::styled(self.speed_label(), Style::default().fg(Color::Yellow)),
-            ]),
-            Line::from(vec![
-                Span::styled("Sp", key_style()),
-                Span::raw(" Play "),
-                Span::styled("R", key_style()),
-                Span::raw(" Reset "),
-                Span::styled("S", key_style()),
-                Span::raw(" Shuf "),
-                Span::styled("↑↓", key_style()),
-                Span::raw(" Spd "),
-                Span::styled("←→", key_style()),
-                Span::raw(" Bars"),
-            ]),
-            Line::from(vec![
-                Span::styled("1", key_style()),
-                Span::raw(" Bub "),
-                Span::styled("2", key_style()),
-                Span::raw(" Qck "),
-                Span::styled("3", key_style()),
-                Span::raw(" Mrg "),
-                Span::styled("4", key_style()),
-                Span::raw(" Sel "),
-                Span::styled("Q", key_style()),
-                Span::raw(" Quit"),
-            ]),
+            ]);
+        }
+        if let Some(Visualization::HashTable(view)) = current_algo.visualization() {
+            summary.extend([
+                Span::raw("Load "),
+                Span::styled(
+                    format!("{:.2}", view.stats.load_factor()),
+                    Style::default().fg(theme.accent),
+                ),
+                Span::raw(" Probes "),
+                Span::styled(
+                    format!("{:.1}/{}", view.stats.average_probe(), view.stats.longest_probe),
+                    Style::default().fg(theme.accent),
+                ),
+                Span::raw(" | "),
+            ]);
+        }
+        summary.extend([
+            Span::styled(status, Style::default().fg(theme.highlight)),
+            Span::raw(" | Speed "),
+            Span::styled(self.speed_label(), Style::default().fg(theme.highlight)),
+        ]);
+
+        let lines = vec![
+            Line::from(summary),
+            Line::from(self.help_spans(HelpLine::Playback, " ")),
+            Line::from(self.help_spans(HelpLine::Navigation, " ")),
         ];
 
         Paragraph::new(lines)
@@ -282,17 +923,1744 @@ impl App {
             .wrap(Wrap { trim: true })
             .render(area, buf);
     }
+
+    fn render_too_small(&self, area: Rect, buf: &mut Buffer) {
+        let theme = self.theme;
+        let lines = vec![
+            Line::styled("Window too small", Style::default().fg(theme.bad)),
+            Line::styled(
+                format!(
+                    "{}×{}, need {}×{}",
+                    area.width, area.height, MIN_VIEWPORT.0, MIN_VIEWPORT.1
+                ),
+                Style::default().fg(theme.muted),
+            ),
+            Line::styled(
+                format!("progress kept at step {}", self.steps),
+                Style::default().fg(theme.muted),
+            ),
+        ];
+        let height = (lines.len() as u16).min(area.height);
+        let area = Rect {
+            y: area.y + (area.height - height) / 2,
+            height,
+            ..area
+        };
+
+        Paragraph::new(lines)
+            .alignment(Alignment::Center)
+            .wrap(Wrap { trim: true })
+            .render(area, buf);
+    }
+
+    fn render_input_prompt(&self, prompt: &InputPrompt, area: Rect, buf: &mut Buffer) {
+        let theme = self.theme;
+        let current_algo = self.get_current_algorithm();
+        let format = format!("Format: {}", self.input_format());
+        let width = centered_rect(area, 60, 0).width.saturating_sub(2).max(1) as usize;
+        let wrapped = |len: usize| len.div_ceil(width).max(1) as u16;
+        let height =
+            5 + wrapped(format.chars().count()) + wrapped(prompt.value.chars().count() + 1);
+        let popup = centered_rect(area, 60, height);
+        Clear.render(popup, buf);
+
+        let block = Block::bordered()
+            .title(match prompt.kind {
+                PromptKind::Input => format!("Input · {}", current_algo.name()),
+                PromptKind::OpenDataset => "Open dataset".to_string(),
+                PromptKind::SaveDataset => "Save dataset".to_string(),
+            })
+            .title_alignment(Alignment::Center)
+            .border_type(BorderType::Rounded);
+
+        let footer = match &prompt.error {
+            Some(error) => Line::styled(error.clone(), Style::default().fg(theme.bad)),
+            None => Line::from(self.key_hints(
+                &[
+                    (
+                        &[Action::Confirm],
+                        match prompt.kind {
+                            PromptKind::Input => "Apply",
+                            PromptKind::OpenDataset => "Open",
+                            PromptKind::SaveDataset => "Save",
+                        },
+                    ),
+                    (&[Action::Cancel], "Cancel"),
+                ],
+                "",
+            )),
+        };
+
+        let lines = vec![
+            Line::styled(format, Style::default().fg(theme.muted)),
+            Line::from(""),
+            Line::from(vec![
+                Span::styled(prompt.value.clone(), Style::default().fg(theme.accent)),
+                Span::styled("▏", key_style(theme)),
+            ]),
+            Line::from(""),
+            footer,
+        ];
+
+        Paragraph::new(lines)
+            .block(block)
+            .wrap(Wrap { trim: false })
+            .render(popup, buf);
+    }
+
+    fn render_palette(&self, palette: &CommandPalette, area: Rect, buf: &mut Buffer) {
+        let theme = self.theme;
+        let popup = centered_rect(area, 60, 18);
+        Clear.render(popup, buf);
+
+        let block = Block::bordered()
+            .title("Commands")
+            .title_alignment(Alignment::Center)
+            .title_bottom(
+                Line::from(self.key_hints(
+                    &[
+                        (&[Action::MoveUp, Action::MoveDown], "Select"),
+                        (&[Action::Confirm], "Run"),
+                        (&[Action::Cancel], "Close"),
+                    ],
+                    " ",
+                ))
+                .alignment(Alignment::Center),
+            )
+            .border_type(BorderType::Rounded);
+        let inner = block.inner(popup);
+        block.render(popup, buf);
+
+        let entries = self.palette_entries();
+        let matches = palette.matches(&entries);
+        let visible = inner.height.saturating_sub(2) as usize;
+        let start = window_start(palette.selected, visible, matches.len());
+        let kind_width = entries
+            .iter()
+            .map(|entry| entry.kind.len())
+            .max()
+            .unwrap_or(0);
+
+        let mut lines = vec![
+            Line::from(vec![
+                Span::styled("› ", key_style(theme)),
+                Span::styled(palette.query.clone(), Style::default().fg(theme.accent)),
+                Span::styled("▏", key_style(theme)),
+            ]),
+            Line::from(""),
+        ];
+        if matches.is_empty() {
+            lines.push(Line::styled(
+                "no matching commands",
+                Style::default().fg(theme.muted),
+            ));
+        }
+        for (index, entry) in matches.iter().enumerate().skip(start).take(visible) {
+            let label_width = (inner.width as usize)
+                .saturating_sub(kind_width + 2 + entry.hint.chars().count() + 1);
+            let label: String = entry.label.chars().take(label_width).collect();
+            let line = Line::from(vec![
+                Span::styled(
+                    format!("{:<kind_width$}  ", entry.kind),
+                    Style::default().fg(theme.muted),
+                ),
+                Span::styled(
+                    format!("{label:<label_width$} "),
+                    Style::default().fg(theme.text),
+                ),
+                Span::styled(entry.hint.clone(), Style::default().fg(theme.dim)),
+            ]);
+            lines.push(if index == palette.selected {
+                line.style(Style::default().fg(theme.inverse).bg(theme.accent))
+            } else {
+                line
+            });
+        }
+
+        Paragraph::new(lines).render(inner, buf);
+    }
+
+    fn picker_rows(&self) -> Vec<PickerRow> {
+        let mut rows = Vec::new();
+        for category in AlgorithmCategory::ALL {
+            rows.push(PickerRow::Category(category));
+            rows.extend(
+                self.algorithms_in_category(category)
+                    .into_iter()
+                    .map(PickerRow::Algorithm),
+            );
+        }
+        rows
+    }
+
+    fn picker_layout(&self, area: Rect, rows: &[PickerRow], highlighted: usize) -> PickerLayout {
+        let name_width = self
+            .algorithms
+            .iter()
+            .map(|algo| algo.name().chars().count() + algo.info().average.chars().count())
+            .max()
+            .unwrap_or(0) as u16
+            + 6;
+        let popup = centered_rect(area, 80, rows.len() as u16 + 2);
+        let inner = Block::bordered().inner(popup);
+        let [list, details] = if inner.width >= name_width + 30 {
+            Layout::default()
+                .direction(Direction::Horizontal)
+                .constraints([Constraint::Length(name_width), Constraint::Min(0)])
+                .areas(inner)
+        } else {
+            Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([Constraint::Min(3), Constraint::Length(6)])
+                .areas(inner)
+        };
+
+        let row = rows
+            .iter()
+            .position(|row| matches!(row, PickerRow::Algorithm(index) if *index == highlighted))
+            .unwrap_or(0);
+        let start = window_start(row, list.height as usize, rows.len());
+
+        PickerLayout {
+            popup,
+            list,
+            details,
+            start,
+        }
+    }
+
+    pub fn picker_hit(&self, column: u16, row: u16) -> Option<Hit> {
+        let highlighted = self.picker?;
+        let rows = self.picker_rows();
+        let layout = self.picker_layout(self.viewport(), &rows, highlighted);
+        let position = Position::new(column, row);
+        if !layout.popup.contains(position) {
+            return None;
+        }
+        if !layout.list.contains(position) {
+            return Some(Hit::Popup);
+        }
+
+        match rows.get(layout.start + (row - layout.list.y) as usize) {
+            Some(PickerRow::Algorithm(index)) => Some(Hit::Algorithm(*index)),
+            _ => Some(Hit::Popup),
+        }
+    }
+
+    fn render_picker(&self, highlighted: usize, area: Rect, buf: &mut Buffer) {
+        let theme = self.theme;
+        let rows = self.picker_rows();
+        let layout = self.picker_layout(area, &rows, highlighted);
+        Clear.render(layout.popup, buf);
+
+        Block::bordered()
+            .title("Algorithms")
+            .title_alignment(Alignment::Center)
+            .title_bottom(
+                Line::from(self.key_hints(
+                    &[
+                        (&[Action::MoveUp, Action::MoveDown], "Move"),
+                        (&[Action::NextCategory], "Category"),
+                        (&[Action::Confirm], "Select"),
+                        (&[Action::Cancel], "Close"),
+                    ],
+                    " ",
+                ))
+                .alignment(Alignment::Center),
+            )
+            .border_type(BorderType::Rounded)
+            .render(layout.popup, buf);
+
+        let width = layout.list.width as usize;
+        let lines: Vec<Line> = rows
+            .iter()
+            .skip(layout.start)
+            .take(layout.list.height as usize)
+            .map(|row| match *row {
+                PickerRow::Category(category) => Line::styled(
+                    category.name(),
+                    Style::default()
+                        .fg(theme.accent)
+                        .add_modifier(Modifier::BOLD),
+                ),
+                PickerRow::Algorithm(index) => {
+                    let algo = &self.algorithms[index];
+                    let marker = if index == self.current_algorithm {
+                        "• "
+                    } else {
+                        "  "
+                    };
+                    let name_width = width.saturating_sub(
+                        marker.chars().count() + algo.info().average.chars().count() + 1,
+                    );
+                    let line = Line::from(vec![
+                        Span::styled(marker, Style::default().fg(theme.good)),
+                        Span::styled(
+                            format!("{:<name_width$}", algo.name()),
+                            Style::default().fg(if self.is_selectable(index) {
+                                theme.text
+                            } else {
+                                theme.muted
+                            }),
+                        ),
+                        Span::styled(algo.info().average, Style::default().fg(theme.muted)),
+                    ]);
+                    if index == highlighted {
+                        line.style(Style::default().fg(theme.inverse).bg(theme.accent))
+                    } else {
+                        line
+                    }
+                }
+            })
+            .collect();
+        Paragraph::new(lines).render(layout.list, buf);
+
+        let algo = &self.algorithms[highlighted];
+        let mut details = vec![
+            Line::styled(
+                algo.name(),
+                Style::default()
+                    .fg(theme.accent)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Line::styled(algo.category().name(), Style::default().fg(theme.muted)),
+            Line::from(""),
+        ];
+        details.extend(info_card(theme, algo));
+        Paragraph::new(details)
+            .block(Block::default().padding(Padding::horizontal(2)))
+            .wrap(Wrap { trim: true })
+            .render(layout.details, buf);
+    }
+
+    fn help_lines(&self) -> Vec<Line<'static>> {
+        let theme = self.theme;
+        let algorithms = self.algorithms_in_category(self.current_category()).len();
+        let keys: Vec<(String, &'static str, bool)> = Action::ALL
+            .into_iter()
+            .map(|action| {
+                let keys = if action == Action::SelectAlgorithm {
+                    self.keymap
+                        .range_label(action, algorithms)
+                        .unwrap_or_default()
+                } else {
+                    self.keymap.keys(action).join(" / ")
+                };
+                (keys, action.description(), self.is_available(action))
+            })
+            .collect();
+        let mouse = [
+            ("Click tab", "Select an algorithm, or the next category"),
+            ("Click bar", "Show its index and value"),
+            ("Drag timeline", "Jump to any step of the run"),
+            ("Scroll", "Speed up / slow down, or scroll the log"),
+        ];
+        let width = keys
+            .iter()
+            .map(|(key, _, _)| key.chars().count())
+            .chain(mouse.iter().map(|(gesture, _)| gesture.chars().count()))
+            .max()
+            .unwrap_or(0);
+
+        let heading = |text: &'static str| {
+            Line::styled(
+                text,
+                Style::default()
+                    .fg(theme.accent)
+                    .add_modifier(Modifier::BOLD),
+            )
+        };
+        let row = |key: &str, description: &'static str, available: bool| {
+            let (key_style, text_style) = if available {
+                (key_style(theme), Style::default().fg(theme.text))
+            } else {
+                (
+                    Style::default().fg(theme.muted),
+                    Style::default().fg(theme.muted),
+                )
+            };
+            Line::from(vec![
+                Span::styled(format!("{key:<width$}  "), key_style),
+                Span::styled(description, text_style),
+            ])
+        };
+
+        let mut lines = vec![heading("Keys")];
+        for (key, description, available) in &keys {
+            lines.push(row(key, description, *available));
+        }
+        lines.push(Line::from(""));
+        lines.push(heading("Mouse"));
+        for (gesture, description) in mouse {
+            lines.push(row(gesture, description, self.mouse));
+        }
+        if !self.mouse {
+            lines.push(Line::styled(
+                "mouse capture is off (mouse = false in the config file)",
+                Style::default().fg(theme.muted),
+            ));
+        }
+        lines
+    }
+
+    fn help_rect(&self, area: Rect, lines: &[Line]) -> Rect {
+        let width = lines.iter().map(Line::width).max().unwrap_or(0) as u16 + 4;
+        centered_size(area, width, lines.len() as u16 + 2)
+    }
+
+    pub fn help_max_offset(&self) -> u16 {
+        let lines = self.help_lines();
+        let visible = self
+            .help_rect(self.viewport(), &lines)
+            .height
+            .saturating_sub(2);
+        (lines.len() as u16).saturating_sub(visible)
+    }
+
+    fn render_help(&self, offset: u16, area: Rect, buf: &mut Buffer) {
+        let theme = self.theme;
+        let lines = self.help_lines();
+        let popup = self.help_rect(area, &lines);
+        Clear.render(popup, buf);
+
+        let scroll = self.keymap.first_keys(&[Action::MoveUp, Action::MoveDown]);
+        let hint = if self.help_max_offset() > 0 && !scroll.is_empty() {
+            format!(" {scroll} scroll · any other key closes ")
+        } else {
+            " any key closes ".to_string()
+        };
+        let block = Block::bordered()
+            .title("Help")
+            .title_alignment(Alignment::Center)
+            .title_bottom(
+                Line::styled(hint, Style::default().fg(theme.muted)).alignment(Alignment::Center),
+            )
+            .border_type(BorderType::Rounded)
+            .padding(Padding::horizontal(1));
+
+        Paragraph::new(lines)
+            .block(block)
+            .scroll((offset.min(self.help_max_offset()), 0))
+            .render(popup, buf);
+    }
+
+    fn stability_verdict(&self) -> Option<(String, Color)> {
+        let theme = self.theme;
+        if !self.has_duplicates() {
+            return None;
+        }
+
+        if self.app_status != AppStatus::Completed {
+            return Some(("Stability: pending".to_string(), theme.muted));
+        }
+
+        Some(match self.inversions? {
+            0 => ("Stable".to_string(), theme.good),
+            1 => (
+                "Unstable: 1 equal-key pair reordered".to_string(),
+                theme.bad,
+            ),
+            count => (
+                format!("Unstable: {count} equal-key pairs reordered"),
+                theme.bad,
+            ),
+        })
+    }
+
+    fn verification_verdict(&self) -> Option<(String, Color)> {
+        let theme = self.theme;
+        let verification = self.verification.as_ref()?;
+        if !verification.is_done() {
+            return Some(("Verifying…".to_string(), theme.accent));
+        }
+
+        Some(match verification.faults() {
+            0 => ("Verified sorted".to_string(), theme.good),
+            1 => ("Not sorted: 1 pair out of order".to_string(), theme.bad),
+            count => (format!("Not sorted: {count} pairs out of order"), theme.bad),
+        })
+    }
+
+    fn help_groups(&self, line: HelpLine) -> impl Iterator<Item = &'static HelpGroup> + '_ {
+        HELP_GROUPS.iter().filter(move |group| {
+            group.line == line
+                && group
+                    .actions
+                    .iter()
+                    .any(|&action| self.is_available(action))
+        })
+    }
+
+    fn key_hints(
+        &self,
+        hints: &[(&'static [Action], &'static str)],
+        padding: &'static str,
+    ) -> Vec<Span<'static>> {
+        let mut spans = vec![Span::raw(padding)];
+        for &(actions, description) in hints {
+            let keys = self.keymap.first_keys(actions);
+            if keys.is_empty() {
+                continue;
+            }
+            if spans.len() > 1 {
+                spans.push(Span::raw("  "));
+            }
+            spans.push(Span::styled(keys, key_style(self.theme)));
+            spans.push(Span::raw(format!(" {description}")));
+        }
+        spans.push(Span::raw(padding));
+        spans
+    }
+
+    fn help_spans(&self, line: HelpLine, gap: &str) -> Vec<Span<'static>> {
+        let mut spans = Vec::new();
+        for group in self.help_groups(line) {
+            spans.push(Span::styled(
+                self.keymap.group_label(group, false),
+                key_style(self.theme),
+            ));
+            spans.push(Span::raw(format!(" {}{gap}", group.short)));
+        }
+        spans
+    }
+}
+
+const RISING: [&str; 8] = [" ", "▁", "▂", "▃", "▄", "▅", "▆", "▇"];
+const FALLING: [&str; 8] = [" ", "▔", "▔", "▀", "▀", "▀", "█", "█"];
+const RISING_HALVES: [[&str; 3]; 3] = [[" ", "▗", "▐"], ["▖", "▄", "▟"], ["▌", "▙", "█"]];
+const FALLING_HALVES: [[&str; 3]; 3] = [[" ", "▝", "▐"], ["▘", "▀", "▜"], ["▌", "▛", "█"]];
+const RIGHTWARD: [&str; 8] = [" ", "▏", "▎", "▍", "▌", "▋", "▊", "▉"];
+const LEFTWARD: [&str; 8] = [" ", "▕", "▕", "▐", "▐", "▐", "█", "█"];
+
+fn render_downsampled(theme: &Theme, view: &ArrayView, area: Rect, buf: &mut Buffer) {
+    let len = view.elements.len();
+    let columns = area.width as usize;
+    if len == 0 || columns == 0 || area.height == 0 {
+        return;
+    }
+
+    let values = view.elements.iter().map(|element| i64::from(element.value));
+    let top = values.clone().max().unwrap_or(0).max(0);
+    let bottom = values.min().unwrap_or(0).min(0);
+    let span = (top - bottom).max(1);
+    let rows = i64::from(area.height);
+    let level = |value: i32| ((i64::from(value) - bottom) * rows * 8 + span / 2) / span;
+    let colors: Vec<Color> = (0..columns)
+        .map(|column| bucket_color(theme, view, bucket(column, len, columns)))
+        .collect();
+
+    let baseline = level(0);
+    for (column, color) in colors.into_iter().enumerate() {
+        let slice = &view.elements[bucket(column, len, columns)];
+        let (Some(low), Some(high)) = (
+            slice.iter().map(|element| element.value).min(),
+            slice.iter().map(|element| element.value).max(),
+        ) else {
+            continue;
+        };
+        let x = area.x + column as u16;
+        let from = level(low).min(rows * 8 - 1);
+        let to = level(high).max(from + 1).min(rows * 8);
+        fill_eighths(
+            buf,
+            x,
+            area,
+            from..to,
+            Style::default().fg(color).add_modifier(Modifier::DIM),
+        );
+        let solid = if low >= 0 {
+            baseline..level(low)
+        } else if high <= 0 {
+            level(high)..baseline
+        } else {
+            continue;
+        };
+        fill_eighths(buf, x, area, solid, Style::default().fg(color));
+    }
+}
+
+fn fill_eighths(buf: &mut Buffer, x: u16, area: Rect, eighths: Range<i64>, style: Style) {
+    let Range {
+        start: from,
+        end: to,
+    } = eighths;
+    for row in from / 8..(to + 7) / 8 {
+        let (floor, ceiling) = (row * 8, row * 8 + 8);
+        let (start, end) = (from.max(floor), to.min(ceiling));
+        let symbol = match (start == floor, end == ceiling) {
+            (true, true) => "█",
+            (true, false) => RISING[(end - floor) as usize],
+            (false, true) => FALLING[(ceiling - start) as usize],
+            (false, false) => "━",
+        };
+        let y = area.bottom() - 1 - row as u16;
+        buf[(x, y)].set_symbol(symbol).set_style(style);
+    }
+}
+
+fn fill_eighths_across(buf: &mut Buffer, y: u16, area: Rect, eighths: Range<i64>, style: Style) {
+    let Range {
+        start: from,
+        end: to,
+    } = eighths;
+    for column in from / 8..(to + 7) / 8 {
+        let (floor, ceiling) = (column * 8, column * 8 + 8);
+        let (start, end) = (from.max(floor), to.min(ceiling));
+        let symbol = match (start == floor, end == ceiling) {
+            (true, true) => "█",
+            (true, false) => RIGHTWARD[(end - floor) as usize],
+            (false, true) => LEFTWARD[(ceiling - start) as usize],
+            (false, false) => "│",
+        };
+        let x = area.x + column as u16;
+        buf[(x, y)].set_symbol(symbol).set_style(style);
+    }
+}
+
+fn render_dots(
+    theme: &Theme,
+    bars: &[(i32, Color)],
+    highlighted: impl Iterator<Item = usize>,
+    spans: &impl Fn(usize) -> Range<u16>,
+    area: Rect,
+    buf: &mut Buffer,
+) {
+    let bottom = bars
+        .iter()
+        .map(|&(value, _)| i64::from(value))
+        .min()
+        .unwrap_or(0);
+    let top = bars
+        .iter()
+        .map(|&(value, _)| i64::from(value))
+        .max()
+        .unwrap_or(0);
+    let span = (top - bottom).max(1);
+    let rows = i64::from(area.height);
+    let y =
+        |value: i64| area.bottom() - 1 - (((value - bottom) * (rows - 1) + span / 2) / span) as u16;
+
+    if bottom < 0 && top > 0 {
+        let zero = y(0);
+        for x in area.left()..area.right() {
+            buf[(x, zero)].set_symbol("┈").set_fg(theme.muted);
+        }
+    }
+    let mut dot = |index: usize| {
+        let Some(&(value, color)) = bars.get(index) else {
+            return;
+        };
+        let columns = spans(index);
+        if columns.is_empty() {
+            return;
+        }
+        let x = columns.start + (columns.end - columns.start - 1) / 2;
+        buf[(x, y(i64::from(value)))].set_symbol("●").set_fg(color);
+    };
+    (0..bars.len()).for_each(&mut dot);
+    highlighted.for_each(dot);
+}
+
+fn render_scatter(theme: &Theme, view: &ArrayView, area: Rect, buf: &mut Buffer) {
+    if area.is_empty() {
+        return;
+    }
+    let mut canvas = Braille::new(area);
+    let (bottom, top) = value_bounds(view);
+    let span = (top - bottom).max(1);
+    let (width, height) = (canvas.width(), canvas.height());
+    let len = view.elements.len();
+
+    for (index, element) in view.elements.iter().enumerate() {
+        let x = (2 * index + 1) * width / (2 * len);
+        let level = (i64::from(element.value) - bottom) * (height as i64 - 1) / span;
+        let state = view.bar_state(index);
+        canvas.plot(
+            x,
+            height - 1 - level as usize,
+            bar_color(theme, state),
+            highlight_priority(state),
+        );
+    }
+    canvas.render(buf);
+}
+
+fn render_gradient(theme: &Theme, view: &ArrayView, area: Rect, buf: &mut Buffer) {
+    let len = view.elements.len();
+    let columns = area.width as usize;
+    if len == 0 || area.is_empty() {
+        return;
+    }
+
+    let (bottom, top) = value_bounds(view);
+    let span = (top - bottom).max(1) as f64;
+    let markers = area.height > 1;
+    for column in 0..columns {
+        let range = bucket(column, len, columns);
+        let slice = &view.elements[range.clone()];
+        let sum: i64 = slice.iter().map(|element| i64::from(element.value)).sum();
+        let mean = sum as f64 / slice.len() as f64;
+        let mut style = Style::default().fg(hue(((mean - bottom as f64) / span).clamp(0.0, 1.0)));
+        if range.clone().all(|index| !view.is_active(index)) {
+            style = style.add_modifier(Modifier::DIM);
+        }
+
+        let x = area.x + column as u16;
+        for y in area.y + u16::from(markers)..area.bottom() {
+            buf[(x, y)].set_symbol("█").set_style(style);
+        }
+        if let Some(state) = bucket_highlight(view, &range).filter(|_| markers) {
+            buf[(x, area.y)]
+                .set_symbol("▼")
+                .set_fg(bar_color(theme, state));
+        }
+    }
+}
+
+fn render_horizontal(
+    theme: &Theme,
+    view: &ArrayView,
+    selected: Option<usize>,
+    area: Rect,
+    buf: &mut Buffer,
+) {
+    let len = view.elements.len();
+    let rows = area.height as usize;
+    if len == 0 || rows == 0 || area.width < 2 {
+        return;
+    }
+
+    let plot = Rect {
+        x: area.x + 1,
+        width: area.width - 1,
+        ..area
+    };
+    let (bottom, top) = value_bounds(view);
+    let (bottom, top) = (bottom.min(0), top.max(0));
+    let span = (top - bottom).max(1);
+    let columns = i64::from(plot.width);
+    let level = |value: i32| ((i64::from(value) - bottom) * columns * 8 + span / 2) / span;
+    let baseline = level(0);
+
+    for row in 0..rows {
+        let range = bucket(row, len, rows);
+        let y = area.y + row as u16;
+        let (marker, color) = if selected.is_some_and(|index| range.contains(&index)) {
+            ("▶", theme.highlight)
+        } else if range.clone().all(|index| view.is_finalized(index)) {
+            ("┃", theme.good)
+        } else if view
+            .active
+            .is_some_and(|(start, end)| range.start < end && start < range.end)
+        {
+            ("│", theme.accent)
+        } else {
+            (" ", theme.text)
+        };
+        buf[(area.x, y)].set_symbol(marker).set_fg(color);
+
+        let color = bucket_color(theme, view, range.clone());
+        let slice = &view.elements[range];
+        let (Some(low), Some(high)) = (
+            slice.iter().map(|element| element.value).min(),
+            slice.iter().map(|element| element.value).max(),
+        ) else {
+            continue;
+        };
+        let from = level(low).min(columns * 8 - 1);
+        let to = level(high).max(from + 1).min(columns * 8);
+        fill_eighths_across(
+            buf,
+            y,
+            plot,
+            from..to,
+            Style::default().fg(color).add_modifier(Modifier::DIM),
+        );
+        let solid = if low >= 0 {
+            baseline..level(low)
+        } else if high <= 0 {
+            level(high)..baseline
+        } else {
+            continue;
+        };
+        fill_eighths_across(buf, y, plot, solid, Style::default().fg(color));
+    }
+}
+
+fn render_disparity(theme: &Theme, view: &ArrayView, sorted: &[i32], area: Rect, buf: &mut Buffer) {
+    let len = view.elements.len();
+    if area.is_empty() {
+        return;
+    }
+    let resorted;
+    let sorted = if sorted.len() == len {
+        sorted
+    } else {
+        resorted = view.sorted_values();
+        &resorted
+    };
+
+    let mut canvas = Braille::new(area);
+    let (width, height) = (canvas.width() as f64, canvas.height() as f64);
+    let radius = (width.min(height) / 2.0 - 1.0).max(0.0);
+    for (index, element) in view.elements.iter().enumerate() {
+        let first = sorted.partition_point(|&value| value < element.value);
+        let last = sorted.partition_point(|&value| value <= element.value);
+        let distance = if index < first {
+            first - index
+        } else {
+            index.saturating_sub(last.saturating_sub(1))
+        };
+        let r = radius * (1.0 - distance as f64 / len as f64);
+        let angle = std::f64::consts::TAU * index as f64 / len as f64 - std::f64::consts::FRAC_PI_2;
+        let state = view.bar_state(index);
+        canvas.plot(
+            (width / 2.0 + r * angle.cos()) as usize,
+            (height / 2.0 + r * angle.sin()) as usize,
+            bar_color(theme, state),
+            highlight_priority(state),
+        );
+    }
+    canvas.render(buf);
+}
+
+struct Braille {
+    area: Rect,
+    cells: Vec<(u8, Color, u8)>,
+}
+
+impl Braille {
+    const DOTS: [[u8; 4]; 2] = [[0x01, 0x02, 0x04, 0x40], [0x08, 0x10, 0x20, 0x80]];
+
+    fn new(area: Rect) -> Self {
+        Self {
+            area,
+            cells: vec![(0, Color::Reset, 0); area.area() as usize],
+        }
+    }
+
+    fn width(&self) -> usize {
+        self.area.width as usize * 2
+    }
+
+    fn height(&self) -> usize {
+        self.area.height as usize * 4
+    }
+
+    fn plot(&mut self, x: usize, y: usize, color: Color, priority: u8) {
+        if x >= self.width() || y >= self.height() {
+            return;
+        }
+        let cell = &mut self.cells[y / 4 * self.area.width as usize + x / 2];
+        if cell.0 == 0 || priority >= cell.2 {
+            cell.1 = color;
+            cell.2 = priority;
+        }
+        cell.0 |= Self::DOTS[x % 2][y % 4];
+    }
+
+    fn render(self, buf: &mut Buffer) {
+        let width = self.area.width as usize;
+        for (index, &(dots, color, _)) in self.cells.iter().enumerate() {
+            if dots == 0 {
+                continue;
+            }
+            let Some(symbol) = char::from_u32(0x2800 + u32::from(dots)) else {
+                continue;
+            };
+            let x = self.area.x + (index % width) as u16;
+            let y = self.area.y + (index / width) as u16;
+            buf[(x, y)].set_char(symbol).set_fg(color);
+        }
+    }
+}
+
+fn value_bounds(view: &ArrayView) -> (i64, i64) {
+    let values = view.elements.iter().map(|element| i64::from(element.value));
+    (values.clone().min().unwrap_or(0), values.max().unwrap_or(0))
+}
+
+fn slot(index: usize, len: usize, slots: usize) -> Range<usize> {
+    if len > slots {
+        let start = index * slots / len;
+        start..start + 1
+    } else {
+        (index * slots).div_ceil(len)..((index + 1) * slots).div_ceil(len)
+    }
+}
+
+fn bucket(slot: usize, len: usize, slots: usize) -> Range<usize> {
+    let start = slot * len / slots;
+    start..((slot + 1) * len / slots).max(start + 1)
+}
+
+fn highlight_priority(state: BarState) -> u8 {
+    match state {
+        BarState::Fault => 5,
+        BarState::Pivot => 4,
+        BarState::Swapped => 3,
+        BarState::Compared => 2,
+        BarState::Current => 1,
+        _ => 0,
+    }
+}
+
+fn bucket_highlight(view: &ArrayView, range: &Range<usize>) -> Option<BarState> {
+    view.highlighted()
+        .filter(|index| range.contains(index))
+        .map(|index| view.bar_state(index))
+        .filter(|&state| highlight_priority(state) > 0)
+        .max_by_key(|&state| highlight_priority(state))
+}
+
+fn bucket_color(theme: &Theme, view: &ArrayView, range: Range<usize>) -> Color {
+    if let Some(state) = bucket_highlight(view, &range) {
+        bar_color(theme, state)
+    } else if range.clone().all(|index| view.is_finalized(index)) {
+        theme.good
+    } else if range.clone().all(|index| !view.is_active(index)) {
+        theme.muted
+    } else {
+        theme.text
+    }
+}
+
+fn hue(fraction: f64) -> Color {
+    let sector = fraction * 5.0;
+    let rising = 1.0 - (sector % 2.0 - 1.0).abs();
+    let (r, g, b) = match sector as u8 {
+        0 => (1.0, rising, 0.0),
+        1 => (rising, 1.0, 0.0),
+        2 => (0.0, 1.0, rising),
+        3 => (0.0, rising, 1.0),
+        _ => (rising, 0.0, 1.0),
+    };
+    let channel = |value: f64| (value * 255.0).round() as u8;
+    Color::Rgb(channel(r), channel(g), channel(b))
+}
+
+fn render_signed_bars(
+    bars: &[(i32, Color)],
+    bar_width: u16,
+    bar_gap: u16,
+    labels: bool,
+    area: Rect,
+    buf: &mut Buffer,
+) {
+    let (bottom, top) = signed_bounds(bars);
+    let label_rows = |present: bool| u16::from(labels && present);
+    let plot = Rect {
+        y: area.y + label_rows(top > 0 || bottom == 0),
+        height: area
+            .height
+            .saturating_sub(label_rows(top > 0 || bottom == 0) + label_rows(bottom < 0)),
+        ..area
+    };
+    if plot.is_empty() {
+        return;
+    }
+
+    let scale = SignedScale::new(bottom, top, plot);
+    let baseline = scale.baseline;
+    let stride = bar_width + bar_gap;
+    for (index, &(value, color)) in bars.iter().enumerate() {
+        let left = area.x + index as u16 * stride;
+        if left >= area.right() {
+            break;
+        }
+        let value = i64::from(value);
+        let height = scale.eighths(value);
+        let full = (height / 8) as u16;
+        let partial = (height % 8) as usize;
+
+        let columns = left..(left + bar_width).min(area.right());
+        for x in columns.clone() {
+            for row in 0..full {
+                let y = if value >= 0 {
+                    baseline - 1 - row
+                } else {
+                    baseline + row
+                };
+                buf[(x, y)].set_symbol("█").set_fg(color);
+            }
+            if partial > 0 {
+                let (y, symbol) = if value >= 0 {
+                    (baseline - 1 - full, RISING[partial])
+                } else {
+                    (baseline + full, FALLING[partial])
+                };
+                buf[(x, y)].set_symbol(symbol).set_fg(color);
+            }
+        }
+
+        if labels {
+            let used = full + u16::from(partial > 0);
+            let y = if value >= 0 {
+                baseline.checked_sub(used + 1)
+            } else {
+                Some(baseline + used)
+            };
+            if let Some(y) = y.filter(|y| (area.y..area.bottom()).contains(y)) {
+                let label = value.to_string();
+                let x = columns.start + bar_width.saturating_sub(label.len() as u16);
+                buf.set_string(x, y, label, Style::default().fg(color));
+            }
+        }
+    }
+}
+
+fn render_half_bars(
+    bars: &[(i32, Color)],
+    priority: impl Fn(usize) -> u8,
+    bar_width: u16,
+    bar_gap: u16,
+    area: Rect,
+    buf: &mut Buffer,
+) {
+    if area.is_empty() {
+        return;
+    }
+
+    let (bottom, top) = signed_bounds(bars);
+    let scale = SignedScale::new(bottom, top, area);
+    let stride = (bar_width + bar_gap) as usize;
+    let half = |unit: usize| {
+        let index = unit / stride;
+        let &(value, color) = bars
+            .get(index)
+            .filter(|_| unit % stride < bar_width as usize)?;
+        let value = i64::from(value);
+        Some((value, scale.eighths(value), color, priority(index)))
+    };
+
+    for x in area.left()..area.right() {
+        let unit = (x - area.x) as usize * 2;
+        let (left, right) = (half(unit), half(unit + 1));
+        for y in area.top()..area.bottom() {
+            let rising = y < scale.baseline;
+            let row = if rising {
+                i64::from(scale.baseline - 1 - y)
+            } else {
+                i64::from(y - scale.baseline)
+            };
+            let fill = |side: Option<(i64, i64, Color, u8)>| match side {
+                Some((value, height, color, rank)) if value != 0 && (value > 0) == rising => {
+                    Some(((height - row * 8).clamp(0, 8), color, rank))
+                        .filter(|&(fill, ..)| fill > 0)
+                }
+                _ => None,
+            };
+            let (left, right) = (fill(left), fill(right));
+            let amount = |side: Option<(i64, Color, u8)>| side.map_or(0, |(fill, ..)| fill);
+            let (left_fill, right_fill) = (amount(left), amount(right));
+
+            let symbol = if left_fill == right_fill {
+                match (left_fill, rising) {
+                    (8, _) => "█",
+                    (_, true) => RISING[left_fill as usize],
+                    (_, false) => FALLING[left_fill as usize],
+                }
+            } else {
+                let halves = |fill: i64| {
+                    if fill == 0 {
+                        0
+                    } else {
+                        ((fill + 2) / 4).max(1) as usize
+                    }
+                };
+                let quadrants = if rising {
+                    RISING_HALVES
+                } else {
+                    FALLING_HALVES
+                };
+                quadrants[halves(left_fill)][halves(right_fill)]
+            };
+            let cell = &mut buf[(x, y)];
+            match (left, right) {
+                (Some((8, left, _)), Some((8, right, _))) if left != right => {
+                    cell.set_symbol("▌").set_fg(left).set_bg(right);
+                }
+                (Some((_, left, left_rank)), Some((_, right, right_rank))) => {
+                    let color = if right_rank > left_rank { right } else { left };
+                    cell.set_symbol(symbol).set_fg(color);
+                }
+                (Some((_, color, _)), None) | (None, Some((_, color, _))) => {
+                    cell.set_symbol(symbol).set_fg(color);
+                }
+                (None, None) => {}
+            }
+        }
+    }
+}
+
+struct SignedScale {
+    span: i64,
+    rows: i64,
+    below: i64,
+    baseline: u16,
+}
+
+impl SignedScale {
+    fn new(bottom: i64, top: i64, plot: Rect) -> Self {
+        let span = (top - bottom).max(1);
+        let rows = i64::from(plot.height);
+        let mut below = (-bottom * rows + span / 2) / span;
+        if bottom < 0 && top > 0 && rows > 1 {
+            below = below.clamp(1, rows - 1);
+        }
+        Self {
+            span,
+            rows,
+            below,
+            baseline: plot.bottom() - below as u16,
+        }
+    }
+
+    fn eighths(&self, value: i64) -> i64 {
+        let room = if value >= 0 {
+            self.rows - self.below
+        } else {
+            self.below
+        };
+        let height = (value.abs() * self.rows * 8 + self.span / 2) / self.span;
+        height.max(i64::from(value != 0)).min(room * 8)
+    }
+}
+
+fn signed_bounds(bars: &[(i32, Color)]) -> (i64, i64) {
+    let values = bars.iter().map(|&(value, _)| i64::from(value));
+    (
+        values.clone().min().unwrap_or(0).min(0),
+        values.max().unwrap_or(0).max(0),
+    )
+}
+
+fn render_range_overlay(
+    theme: &Theme,
+    view: &ArrayView,
+    selected: Option<usize>,
+    columns: impl Fn(usize) -> Range<u16>,
+    area: Rect,
+    buf: &mut Buffer,
+) {
+    if area.is_empty() {
+        return;
+    }
+
+    let len = view.elements.len();
+    let mut mark = |x: u16, symbol: &str, color: Color| {
+        if let Some(cell) = buf.cell_mut((x, area.y)) {
+            cell.set_symbol(symbol).set_fg(color);
+        }
+    };
+    let mut bracket = |start: usize, end: usize, color: Color| {
+        let end = end.min(len);
+        if start >= end {
+            return;
+        }
+        let (left, right) = (columns(start).start, columns(end - 1).end);
+        if left >= right {
+            return;
+        }
+        let right = right - 1;
+        for x in left..=right {
+            let symbol = if left == right {
+                "┴"
+            } else if x == left {
+                "└"
+            } else if x == right {
+                "┘"
+            } else {
+                "─"
+            };
+            mark(x, symbol, color);
+        }
+    };
+
+    if let Some(width) = view.sorted_run {
+        let mut drawn_to = None;
+        for start in (0..len).step_by(width) {
+            if drawn_to.is_some_and(|right| columns(start).start < right) {
+                continue;
+            }
+            drawn_to = Some(columns((start + width).min(len) - 1).end);
+            bracket(start, start + width, theme.muted);
+        }
+    }
+    if let Some((start, end)) = view.active {
+        bracket(start, end, theme.accent);
+    }
+    let mut previous = None;
+    for index in (0..len).filter(|&index| view.is_finalized(index)) {
+        let range = columns(index);
+        if previous.as_ref() == Some(&range) {
+            continue;
+        }
+        for x in range.clone() {
+            mark(x, "━", theme.good);
+        }
+        previous = Some(range);
+    }
+    if let Some(index) = selected.filter(|&index| index < len) {
+        for x in columns(index) {
+            mark(x, "▲", theme.highlight);
+        }
+    }
+}
+
+fn info_card(theme: &Theme, algo: &AlgorithmType) -> Vec<Line<'static>> {
+    let info = algo.info();
+    let value = |text: &'static str| Span::styled(text, Style::default().fg(theme.highlight));
+    let time = match info.per {
+        Some(per) => format!("Time per {per}: "),
+        None => "Time: ".to_string(),
+    };
+
+    let mut properties = vec![Span::raw("Space: "), value(info.space)];
+    for (flag, yes, no) in [
+        (info.stable, "stable", "unstable"),
+        (info.in_place, "in-place", "not in-place"),
+        (info.adaptive, "adaptive", "not adaptive"),
+    ] {
+        if let Some(flag) = flag {
+            properties.push(Span::raw(" · "));
+            properties.push(Span::styled(
+                if flag { yes } else { no },
+                Style::default().fg(if flag { theme.good } else { theme.dim }),
+            ));
+        }
+    }
+
+    vec![
+        Line::from(vec![
+            Span::raw(time),
+            Span::styled("best ", Style::default().fg(theme.muted)),
+            value(info.best),
+            Span::styled(" · avg ", Style::default().fg(theme.muted)),
+            value(info.average),
+            Span::styled(" · worst ", Style::default().fg(theme.muted)),
+            value(info.worst),
+        ]),
+        Line::from(properties),
+        Line::styled(info.description, Style::default().fg(theme.dim)),
+    ]
+}
+
+fn render_table(theme: &Theme, table: &DpTable, area: Rect, buf: &mut Buffer) {
+    let result_height = if table.result.is_some() { 2 } else { 0 };
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(1), Constraint::Length(result_height)])
+        .split(area);
+
+    let label_width = table
+        .row_labels
+        .iter()
+        .map(|label| label.chars().count())
+        .max()
+        .unwrap_or(1) as u16;
+    let cell_width = table
+        .col_labels
+        .iter()
+        .map(|label| label.chars().count())
+        .chain(
+            table
+                .cells
+                .iter()
+                .flatten()
+                .map(|&cell| cell_text(cell).chars().count()),
+        )
+        .max()
+        .unwrap_or(1)
+        .max(2) as u16;
+
+    let visible_cols = (chunks[0].width.saturating_sub(label_width + 1) / (cell_width + 1)).max(1);
+    let visible_rows = chunks[0].height.saturating_sub(1).max(1);
+    let (focus_row, focus_col) = table.current.unwrap_or((0, 0));
+    let col_start = window_start(focus_col, visible_cols as usize, table.cols());
+    let row_start = window_start(focus_row, visible_rows as usize, table.rows());
+    let cols = col_start..(col_start + visible_cols as usize).min(table.cols());
+    let rows = row_start..(row_start + visible_rows as usize).min(table.rows());
+
+    let header_style = |active: bool| {
+        if active {
+            key_style(theme)
+        } else {
+            Style::default().fg(theme.muted)
+        }
+    };
+
+    let header = Row::new(
+        std::iter::once(Cell::from("")).chain(cols.clone().map(|col| {
+            Cell::from(Line::from(table.col_labels[col].clone()).alignment(Alignment::Right))
+                .style(header_style(table.current.is_some_and(|(_, c)| c == col)))
+        })),
+    );
+
+    let body = rows.map(|row| {
+        let label = Cell::from(table.row_labels[row].clone())
+            .style(header_style(table.current.is_some_and(|(r, _)| r == row)));
+
+        Row::new(std::iter::once(label).chain(cols.clone().map(|col| {
+            let cell = table.cells[row][col];
+            let style = if table.current == Some((row, col)) {
+                Style::default()
+                    .fg(theme.inverse)
+                    .bg(theme.highlight)
+                    .add_modifier(Modifier::BOLD)
+            } else if table.dependencies.contains(&(row, col)) {
+                Style::default().fg(theme.inverse).bg(theme.accent)
+            } else if cell == DpCell::Empty {
+                Style::default().fg(theme.muted)
+            } else {
+                Style::default().fg(theme.text)
+            };
+
+            Cell::from(Line::from(cell_text(cell)).alignment(Alignment::Right)).style(style)
+        })))
+    });
+
+    let widths = std::iter::once(Constraint::Length(label_width))
+        .chain(cols.clone().map(|_| Constraint::Length(cell_width)));
+
+    Widget::render(
+        Table::new(body, widths).header(header).column_spacing(1),
+        chunks[0],
+        buf,
+    );
+
+    if let Some(result) = &table.result {
+        Paragraph::new(Line::styled(
+            result.clone(),
+            Style::default().fg(theme.good).add_modifier(Modifier::BOLD),
+        ))
+        .alignment(Alignment::Center)
+        .wrap(Wrap { trim: true })
+        .render(chunks[1], buf);
+    }
+}
+
+fn render_text_match(theme: &Theme, view: &TextMatch, area: Rect, buf: &mut Buffer) {
+    let label_width = ["index", "text", "pattern"]
+        .iter()
+        .map(|label| label.chars().count())
+        .chain(view.rows.iter().map(|row| row.label.chars().count()))
+        .chain(view.tables.iter().map(|table| table.label.chars().count()))
+        .max()
+        .unwrap_or(1);
+    let cell_width = view
+        .rows
+        .iter()
+        .flat_map(|row| &row.cells)
+        .map(|cell| cell.chars().count())
+        .chain(std::iter::once(view.text.len().saturating_sub(1).to_string().len()))
+        .max()
+        .unwrap_or(1)
+        + 1;
+
+    let visible = (area.width as usize)
+        .saturating_sub(label_width + 1)
+        .checked_div(cell_width)
+        .unwrap_or(0)
+        .max(1);
+    let focus = view.alignment + view.compared.map_or(0, |(index, _)| index);
+    let start = window_start(focus, visible, view.text.len());
+    let columns = start..(start + visible).min(view.text.len());
+    let pattern_end = view.alignment + view.pattern.len();
+
+    let compared_style = |matched: bool| {
+        Style::default()
+            .fg(theme.inverse)
+            .bg(if matched { theme.good } else { theme.bad })
+            .add_modifier(Modifier::BOLD)
+    };
+
+    let mut lines = vec![
+        grid_line(
+            theme,
+            "index",
+            label_width,
+            cell_width,
+            columns
+                .clone()
+                .map(|column| (column.to_string(), Style::default().fg(theme.muted))),
+        ),
+        grid_line(
+            theme,
+            "text",
+            label_width,
+            cell_width,
+            columns.clone().map(|column| {
+                let style = match view.compared {
+                    Some((index, matched)) if column == view.alignment + index => {
+                        compared_style(matched)
+                    }
+                    _ if view
+                        .matches
+                        .iter()
+                        .any(|&start| (start..start + view.pattern.len()).contains(&column)) =>
+                    {
+                        Style::default()
+                            .fg(theme.accent)
+                            .add_modifier(Modifier::BOLD | Modifier::UNDERLINED)
+                    }
+                    _ => Style::default().fg(theme.text),
+                };
+                (view.text[column].to_string(), style)
+            }),
+        ),
+        grid_line(
+            theme,
+            "pattern",
+            label_width,
+            cell_width,
+            columns.clone().map(|column| {
+                if !(view.alignment..pattern_end).contains(&column) {
+                    return (String::new(), Style::default());
+                }
+
+                let index = column - view.alignment;
+                let style = match view.compared {
+                    Some((compared, matched)) if compared == index => compared_style(matched),
+                    _ if view.confirmed.contains(&index) => Style::default().fg(theme.good),
+                    _ => Style::default().fg(theme.text),
+                };
+                (view.pattern[index].to_string(), style)
+            }),
+        ),
+    ];
+
+    for row in &view.rows {
+        lines.push(grid_line(
+            theme,
+            &row.label,
+            label_width,
+            cell_width,
+            columns.clone().map(|column| {
+                let Some(index) = column.checked_sub(row.offset) else {
+                    return (String::new(), Style::default());
+                };
+                let Some(cell) = row.cells.get(index) else {
+                    return (String::new(), Style::default());
+                };
+                let style = if row.highlight == Some(index) {
+                    key_style(theme)
+                } else {
+                    Style::default().fg(theme.dim)
+                };
+                (cell.clone(), style)
+            }),
+        ));
+    }
+
+    for table in &view.tables {
+        let width = table
+            .headers
+            .iter()
+            .chain(&table.values)
+            .map(|cell| cell.chars().count())
+            .max()
+            .unwrap_or(1)
+            + 1;
+
+        lines.push(Line::from(""));
+        lines.push(grid_line(
+            theme,
+            &table.label,
+            label_width,
+            width,
+            table
+                .headers
+                .iter()
+                .map(|header| (header.clone(), Style::default().fg(theme.muted))),
+        ));
+        lines.push(grid_line(
+            theme,
+            "",
+            label_width,
+            width,
+            table.values.iter().enumerate().map(|(index, value)| {
+                let style = if table.highlight == Some(index) {
+                    key_style(theme)
+                } else {
+                    Style::default().fg(theme.dim)
+                };
+                (value.clone(), style)
+            }),
+        ));
+    }
+
+    lines.push(Line::from(""));
+    lines.push(Line::styled(
+        view.note.clone(),
+        Style::default().fg(theme.highlight),
+    ));
+
+    Paragraph::new(lines)
+        .wrap(Wrap { trim: false })
+        .render(area, buf);
+}
+
+fn render_hash_table(theme: &Theme, view: &HashTableView, area: Rect, buf: &mut Buffer) {
+    let table_count = view.tables.len();
+    let has_chain = table_count == 1 && view.tables[0].iter().any(|bucket| bucket.len() > 1);
+    let detail_height = (table_count * 2 + usize::from(has_chain) + 2) as u16;
+    let [charts_area, detail_area] = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(3), Constraint::Length(detail_height)])
+        .areas(area);
+
+    let chart_areas = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints(vec![Constraint::Ratio(1, table_count as u32); table_count])
+        .split(charts_area);
+
+    for (table, chart_area) in chart_areas.iter().enumerate() {
+        render_hash_loads(theme, view, table, *chart_area, buf);
+    }
+
+    let label_width = "slot".len().max(format!("table {table_count}").len());
+    let cell_width = view
+        .tables
+        .iter()
+        .flatten()
+        .map(|bucket| slot_text(bucket).chars().count())
+        .chain(std::iter::once(view.tables[0].len().to_string().len()))
+        .max()
+        .unwrap_or(1)
+        + 1;
+    let visible = (detail_area.width as usize)
+        .saturating_sub(label_width + 1)
+        .checked_div(cell_width)
+        .unwrap_or(0)
+        .max(1);
+    let capacity = view.tables[0].len();
+    let focus = view.probe.map_or(0, |(_, slot)| slot);
+    let start = window_start(focus, visible, capacity);
+    let columns = start..(start + visible).min(capacity);
+
+    let mut lines = Vec::new();
+    for (table, slots) in view.tables.iter().enumerate() {
+        let label = if table_count == 1 {
+            "slot".to_string()
+        } else {
+            format!("table {}", table + 1)
+        };
+        lines.push(grid_line(
+            theme,
+            &label,
+            label_width,
+            cell_width,
+            columns
+                .clone()
+                .map(|column| (column.to_string(), Style::default().fg(theme.muted))),
+        ));
+        lines.push(grid_line(
+            theme,
+            "",
+            label_width,
+            cell_width,
+            columns.clone().map(|column| {
+                let style = if view.probe == Some((table, column)) {
+                    key_style(theme)
+                } else if view.path.contains(&(table, column)) {
+                    Style::default().fg(theme.accent)
+                } else if slots[column].is_empty() {
+                    Style::default().fg(theme.muted)
+                } else {
+                    Style::default().fg(theme.text)
+                };
+                (slot_text(&slots[column]), style)
+            }),
+        ));
+    }
+
+    if has_chain {
+        let (slot, bucket) = match view.probe {
+            Some((_, slot)) => (slot, &view.tables[0][slot]),
+            None => view.tables[0]
+                .iter()
+                .enumerate()
+                .max_by_key(|(_, bucket)| bucket.len())
+                .unwrap_or((0, &view.tables[0][0])),
+        };
+        let chain: Vec<String> = bucket.iter().map(|key| key.to_string()).collect();
+        lines.push(Line::from(vec![
+            Span::styled(format!("bucket {slot}: "), Style::default().fg(theme.muted)),
+            Span::styled(
+                if chain.is_empty() {
+                    "∅".to_string()
+                } else {
+                    chain.join(" → ")
+                },
+                Style::default().fg(theme.accent),
+            ),
+        ]));
+    }
+
+    lines.push(Line::from(""));
+    lines.push(Line::styled(
+        view.note.clone(),
+        Style::default().fg(theme.highlight),
+    ));
+
+    Paragraph::new(lines)
+        .wrap(Wrap { trim: false })
+        .render(detail_area, buf);
+}
+
+fn render_hash_loads(
+    theme: &Theme,
+    view: &HashTableView,
+    table: usize,
+    area: Rect,
+    buf: &mut Buffer,
+) {
+    let loads = &view.loads[table];
+    let group = loads.len().div_ceil(area.width.max(1) as usize).max(1);
+    let (bar_width, bar_gap) = if loads.len() * 2 <= area.width as usize {
+        (((area.width as usize / loads.len()).saturating_sub(1).max(1)) as u16, 1)
+    } else {
+        (1, 0)
+    };
+
+    let bars: Vec<Bar> = loads
+        .chunks(group)
+        .enumerate()
+        .map(|(index, chunk)| {
+            let slots = index * group..index * group + chunk.len();
+            let style = if view
+                .probe
+                .is_some_and(|(probe_table, slot)| probe_table == table && slots.contains(&slot))
+            {
+                Style::default().fg(theme.highlight)
+            } else if view
+                .path
+                .iter()
+                .any(|&(path_table, slot)| path_table == table && slots.contains(&slot))
+            {
+                Style::default().fg(theme.accent)
+            } else {
+                Style::default().fg(theme.text)
+            };
+
+            Bar::default()
+                .value(chunk.iter().copied().max().unwrap_or(0))
+                .text_value(String::new())
+                .style(style)
+        })
+        .collect();
+
+    let max = loads.iter().copied().max().unwrap_or(0).max(1);
+    BarChart::default()
+        .data(BarGroup::default().bars(&bars))
+        .max(max)
+        .bar_width(bar_width)
+        .bar_gap(bar_gap)
+        .render(area, buf);
+}
+
+fn slot_text(bucket: &[i32]) -> String {
+    match bucket {
+        [] => "·".to_string(),
+        [key] => key.to_string(),
+        [key, rest @ ..] => format!("{key}+{}", rest.len()),
+    }
+}
+
+fn grid_line(
+    theme: &Theme,
+    label: &str,
+    label_width: usize,
+    cell_width: usize,
+    cells: impl Iterator<Item = (String, Style)>,
+) -> Line<'static> {
+    let mut spans = vec![Span::styled(
+        format!("{label:<label_width$} "),
+        Style::default().fg(theme.muted),
+    )];
+    spans.extend(cells.map(|(text, style)| {
+        Span::styled(format!("{text:>cell_width$}"), style)
+    }));
+
+    Line::from(spans)
+}
+
+fn cell_text(cell: DpCell) -> String {
+    match cell {
+        DpCell::Empty => "·".to_string(),
+        DpCell::Value(value) => value.to_string(),
+        DpCell::Infinite => "∞".to_string(),
+    }
+}
+
+fn window_start(focus: usize, visible: usize, total: usize) -> usize {
+    if total <= visible {
+        0
+    } else {
+        focus.saturating_sub(visible / 2).min(total - visible)
+    }
+}
+
+fn centered_rect(area: Rect, width_percent: u16, height: u16) -> Rect {
+    centered_size(area, (area.width * width_percent / 100).max(30), height)
+}
+
+fn centered_size(area: Rect, width: u16, height: u16) -> Rect {
+    let width = width.min(area.width);
+    let height = height.min(area.height);
+
+    Rect {
+        x: area.x + (area.width - width) / 2,
+        y: area.y + (area.height - height) / 2,
+        width,
+        height,
+    }
+}
+
+fn bar_color(theme: &Theme, state: BarState) -> Color {
+    match state {
+        BarState::Idle => theme.text,
+        BarState::Outside => theme.muted,
+        BarState::Sorted => theme.good,
+        BarState::Current => theme.accent,
+        BarState::Compared => theme.highlight,
+        BarState::Swapped => theme.bad,
+        BarState::Pivot => theme.special,
+        BarState::Fault => theme.bad,
+    }
+}
+
+fn bar_legend(theme: &Theme) -> Line<'static> {
+    let mut spans = Vec::new();
+    for (state, name) in [
+        (BarState::Compared, "compared"),
+        (BarState::Swapped, "swapped/written"),
+        (BarState::Pivot, "pivot"),
+        (BarState::Sorted, "sorted"),
+        (BarState::Outside, "outside range"),
+    ] {
+        spans.push(Span::styled(
+            " ■ ",
+            Style::default().fg(bar_color(theme, state)),
+        ));
+        spans.push(Span::raw(name));
+    }
+    spans.push(Span::styled(" └┘ ", Style::default().fg(theme.accent)));
+    spans.push(Span::raw("active range "));
+
+    Line::from(spans).alignment(Alignment::Center)
 }
 
-fn key_style() -> Style {
+fn key_style(theme: &Theme) -> Style {
     Style::default()
-        .fg(Color::Yellow)
+        .fg(theme.highlight)
         .add_modifier(Modifier::BOLD)
 }
 
-fn control_line(key: &str, action: &str) -> Line<'static> {
+fn control_line(theme: &Theme, key: &str, action: &str) -> Line<'static> {
     Line::from(vec![
-        Span::styled(key.to_string(), key_style()),
+        Span::styled(key.to_string(), key_style(theme)),
         Span::raw(format!(" - {action}")),
     ])
 }
@@ -0,0 +1,188 @@
+use crate::algorithms::{ArrayView, Element, StepEvent};
+use std::time::{Duration, Instant};
+
+const SWEEP_DURATION: Duration = Duration::from_millis(1_000);
+const MAX_MARKED_FAULTS: usize = 256;
+
+#[derive(Debug)]
+pub struct Verification {
+    checked: usize,
+    faults: usize,
+    first_fault: Option<usize>,
+    done: bool,
+    started: Instant,
+    head: Vec<usize>,
+    finalized: Vec<bool>,
+    events: Vec<StepEvent>,
+}
+
+impl Verification {
+    pub fn new(len: usize) -> Self {
+        Self {
+            checked: 0,
+            faults: 0,
+            first_fault: None,
+            done: false,
+            started: Instant::now(),
+            head: Vec::new(),
+            finalized: vec![false; len],
+            events: Vec::new(),
+        }
+    }
+
+    pub fn is_done(&self) -> bool {
+        self.done
+    }
+
+    pub fn faults(&self) -> usize {
+        self.faults
+    }
+
+    pub fn first_fault(&self) -> Option<usize> {
+        self.first_fault
+    }
+
+    pub fn sweep(&mut self, elements: &[Element]) -> bool {
+        if self.done {
+            return false;
+        }
+
+        let pairs = elements.len().saturating_sub(1);
+        let progress = self.started.elapsed().as_secs_f64() / SWEEP_DURATION.as_secs_f64();
+        let target = ((pairs as f64 * progress).ceil() as usize).clamp(self.checked, pairs);
+        for index in self.checked..target {
+            if elements[index].value > elements[index + 1].value {
+                self.faults += 1;
+                self.first_fault.get_or_insert(index);
+                if self.faults <= MAX_MARKED_FAULTS {
+                    self.events.push(StepEvent::OutOfOrder(index, index + 1));
+                }
+            }
+            self.finalized[index] = true;
+        }
+        self.checked = target;
+
+        self.done = self.checked == pairs;
+        if self.done {
+            self.finalized.fill(true);
+            self.head.clear();
+        } else {
+            self.head = vec![self.checked, self.checked + 1];
+        }
+        self.done
+    }
+
+    pub fn view<'a>(&'a self, view: ArrayView<'a>) -> ArrayView<'a> {
+        ArrayView {
+            current: &self.head,
+            events: &self.events,
+            pivot: None,
+            finalized: &self.finalized,
+            active: None,
+            sorted_run: None,
+            ..view
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::BarState;
+
+    fn elements(values: &[i32]) -> Vec<Element> {
+        values
+            .iter()
+            .enumerate()
+            .map(|(origin, &value)| Element { value, origin })
+            .collect()
+    }
+
+    fn elapsed(len: usize) -> Verification {
+        let mut verification = Verification::new(len);
+        verification.started = Instant::now()
+            .checked_sub(SWEEP_DURATION)
+            .expect("clock is past the sweep duration");
+        verification
+    }
+
+    fn array_view(elements: &[Element]) -> ArrayView<'_> {
+        ArrayView {
+            elements,
+            current: &[],
+            events: &[],
+            pivot: None,
+            finalized: &[],
+            active: None,
+            sorted_run: None,
+        }
+    }
+
+    #[test]
+    fn sweep_passes_a_sorted_array() {
+        let elements = elements(&[1, 2, 2, 5, 9]);
+        let mut verification = elapsed(elements.len());
+
+        assert!(verification.sweep(&elements));
+        assert!(verification.is_done());
+        assert_eq!(verification.faults(), 0);
+        assert_eq!(verification.first_fault(), None);
+
+        let view = verification.view(array_view(&elements));
+        assert!(view.events.is_empty());
+        assert!((0..elements.len()).all(|index| view.bar_state(index) == BarState::Sorted));
+        assert!(!verification.sweep(&elements));
+    }
+
+    #[test]
+    fn sweep_flags_each_pair_out_of_order() {
+        let elements = elements(&[1, 3, 2, 5, 4]);
+        let mut verification = elapsed(elements.len());
+
+        assert!(verification.sweep(&elements));
+        assert_eq!(verification.faults(), 2);
+        assert_eq!(verification.first_fault(), Some(1));
+
+        let view = verification.view(array_view(&elements));
+        assert_eq!(
+            view.events,
+            [StepEvent::OutOfOrder(1, 2), StepEvent::OutOfOrder(3, 4)]
+        );
+        assert_eq!(view.bar_state(0), BarState::Sorted);
+        assert_eq!(view.bar_state(2), BarState::Fault);
+    }
+
+    #[test]
+    fn sweep_advances_with_time() {
+        let elements = elements(&(0..1_000).collect::<Vec<_>>());
+        let mut verification = Verification::new(elements.len());
+
+        assert!(!verification.sweep(&elements));
+        assert!(!verification.is_done());
+        let view = verification.view(array_view(&elements));
+        assert_eq!(view.current.len(), 2);
+        assert!(!view.is_finalized(elements.len() - 1));
+    }
+
+    #[test]
+    fn sweep_marks_a_bounded_number_of_faults() {
+        let elements = elements(&(0..1_000).rev().collect::<Vec<_>>());
+        let mut verification = elapsed(elements.len());
+
+        assert!(verification.sweep(&elements));
+        assert_eq!(verification.faults(), 999);
+        assert_eq!(verification.first_fault(), Some(0));
+        assert_eq!(verification.events.len(), MAX_MARKED_FAULTS);
+    }
+
+    #[test]
+    fn sweep_finishes_at_once_without_pairs() {
+        for values in [&[][..], &[7]] {
+            let elements = elements(values);
+            let mut verification = Verification::new(elements.len());
+
+            assert!(verification.sweep(&elements));
+            assert_eq!(verification.faults(), 0);
+        }
+    }
+}